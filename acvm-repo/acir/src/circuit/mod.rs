@@ -3,7 +3,9 @@ pub mod brillig;
 pub mod directives;
 pub mod opcodes;
 
-use crate::native_types::Witness;
+use self::brillig::{BrilligInputs, BrilligOutputs};
+use self::directives::Directive;
+use crate::native_types::{Expression, Witness};
 pub use opcodes::Opcode;
 use thiserror::Error;
 
@@ -13,7 +15,7 @@ use base64::Engine;
 use flate2::Compression;
 use serde::{de::Error as DeserializationError, Deserialize, Deserializer, Serialize, Serializer};
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Specifies the maximum width of the expressions which will be constrained.
 ///
@@ -159,6 +161,242 @@ impl Circuit {
             self.public_parameters.0.union(&self.return_values.0).cloned().collect();
         PublicInputs(public_inputs)
     }
+
+    /// Checks that every witness referenced by the circuit lies within `0..=current_witness_index`.
+    ///
+    /// Backends size their witness vectors using [`Circuit::num_vars`] so a witness outside of this range
+    /// results in confusing errors at proving time. Callers can run this check before handing the circuit over.
+    pub fn validate(&self) -> Result<(), CircuitValidationError> {
+        let num_vars = self.num_vars();
+
+        let parameters = self.private_parameters.iter().chain(&self.public_parameters.0);
+        for witness in parameters.chain(&self.return_values.0) {
+            if witness.witness_index() >= num_vars {
+                return Err(CircuitValidationError::InvalidInputWitness {
+                    witness: witness.witness_index(),
+                    num_vars,
+                });
+            }
+        }
+
+        for (index, opcode) in self.opcodes.iter().enumerate() {
+            let invalid_witness = opcode_witnesses(opcode)
+                .into_iter()
+                .find(|witness| witness.witness_index() >= num_vars);
+            if let Some(witness) = invalid_witness {
+                return Err(CircuitValidationError::InvalidOpcodeWitness {
+                    opcode_location: OpcodeLocation::Acir(index),
+                    witness: witness.witness_index(),
+                    num_vars,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a new circuit containing the opcodes of `self` followed by the opcodes of `other`.
+    ///
+    /// Every witness referenced by `other` (including its inputs and return values) is shifted by `witness_offset`
+    /// so that passing an offset of at least [`Circuit::num_vars`] guarantees that the two circuits share no witnesses.
+    /// Smaller offsets may be used to deliberately wire witnesses of `other` to witnesses of `self`.
+    /// Memory blocks and BigInts of `other` are renumbered so that they never alias those of `self`.
+    pub fn concat(&self, other: &Circuit, witness_offset: u32) -> Circuit {
+        let shift_witness = |witness: &Witness| Witness(witness.witness_index() + witness_offset);
+        let block_offset = self
+            .opcodes
+            .iter()
+            .filter_map(|opcode| match opcode {
+                Opcode::MemoryInit { block_id, .. } => Some(block_id.0 + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default();
+        let bigint_offset = self
+            .opcodes
+            .iter()
+            .flat_map(|opcode| match opcode {
+                Opcode::BlackBoxFuncCall(func) => {
+                    let mut func = func.clone();
+                    func.bigint_ids_mut().into_iter().map(|id| *id + 1).collect()
+                }
+                _ => Vec::new(),
+            })
+            .max()
+            .unwrap_or_default();
+
+        let mut opcodes = self.opcodes.clone();
+        opcodes.extend(other.opcodes.iter().cloned().map(|mut opcode| {
+            let mut shift_witness = |witness: &mut Witness| witness.0 += witness_offset;
+            remap_opcode_witnesses(&mut opcode, &mut shift_witness, block_offset, bigint_offset);
+            opcode
+        }));
+
+        let mut assert_messages = self.assert_messages.clone();
+        assert_messages.extend(other.assert_messages.iter().map(|(location, message)| {
+            let location = match *location {
+                OpcodeLocation::Acir(index) => OpcodeLocation::Acir(index + self.opcodes.len()),
+                OpcodeLocation::Brillig { acir_index, brillig_index } => OpcodeLocation::Brillig {
+                    acir_index: acir_index + self.opcodes.len(),
+                    brillig_index,
+                },
+            };
+            (location, message.clone())
+        }));
+
+        let mut private_parameters = self.private_parameters.clone();
+        private_parameters.extend(other.private_parameters.iter().map(shift_witness));
+        let mut public_parameters = self.public_parameters.clone();
+        public_parameters.0.extend(other.public_parameters.0.iter().map(shift_witness));
+        let mut return_values = self.return_values.clone();
+        return_values.0.extend(other.return_values.0.iter().map(shift_witness));
+
+        Circuit {
+            current_witness_index: self
+                .current_witness_index
+                .max(other.current_witness_index + witness_offset),
+            opcodes,
+            expression_width: self.expression_width,
+            private_parameters,
+            public_parameters,
+            return_values,
+            assert_messages,
+            recursive: self.recursive || other.recursive,
+        }
+    }
+
+    /// Renumbers the witnesses of the circuit so that they form the contiguous range `0..n`,
+    /// keeping their relative order. Returns the compacted circuit along with a map from each
+    /// witness of `self` to its new witness.
+    ///
+    /// Optimizations can leave gaps in the witness indices but backends size their witness vectors
+    /// using [`Circuit::num_vars`], so each unused index still costs a variable.
+    pub fn compact_witnesses(&self) -> (Circuit, BTreeMap<Witness, Witness>) {
+        let parameters = self.private_parameters.iter().chain(&self.public_parameters.0);
+        let mut witnesses: BTreeSet<Witness> =
+            parameters.chain(&self.return_values.0).copied().collect();
+        witnesses.extend(self.opcodes.iter().flat_map(opcode_witnesses));
+
+        let witness_map: BTreeMap<Witness, Witness> = witnesses
+            .into_iter()
+            .enumerate()
+            .map(|(index, witness)| (witness, Witness(index as u32)))
+            .collect();
+
+        let mut remap_witness = |witness: &mut Witness| *witness = witness_map[&*witness];
+        let opcodes = self
+            .opcodes
+            .iter()
+            .cloned()
+            .map(|mut opcode| {
+                remap_opcode_witnesses(&mut opcode, &mut remap_witness, 0, 0);
+                opcode
+            })
+            .collect();
+        let remap_witnesses = |witnesses: &BTreeSet<Witness>| -> BTreeSet<Witness> {
+            witnesses.iter().map(|witness| witness_map[witness]).collect()
+        };
+
+        let circuit = Circuit {
+            current_witness_index: witness_map.len().saturating_sub(1) as u32,
+            opcodes,
+            expression_width: self.expression_width,
+            private_parameters: remap_witnesses(&self.private_parameters),
+            public_parameters: PublicInputs(remap_witnesses(&self.public_parameters.0)),
+            return_values: PublicInputs(remap_witnesses(&self.return_values.0)),
+            assert_messages: self.assert_messages.clone(),
+            recursive: self.recursive,
+        };
+        (circuit, witness_map)
+    }
+}
+
+/// Rewrites every witness referenced by `opcode` using `map_witness` and shifts every memory
+/// block by `block_offset` and every BigInt by `bigint_offset`.
+fn remap_opcode_witnesses(
+    opcode: &mut Opcode,
+    map_witness: &mut dyn FnMut(&mut Witness),
+    block_offset: u32,
+    bigint_offset: u32,
+) {
+    fn remap_expression(expr: &mut Expression, map_witness: &mut dyn FnMut(&mut Witness)) {
+        for (_, lhs, rhs) in &mut expr.mul_terms {
+            map_witness(lhs);
+            map_witness(rhs);
+        }
+        for (_, witness) in &mut expr.linear_combinations {
+            map_witness(witness);
+        }
+    }
+
+    match opcode {
+        Opcode::AssertZero(expr) => remap_expression(expr, map_witness),
+        Opcode::BlackBoxFuncCall(func) => {
+            func.witnesses_mut().into_iter().for_each(map_witness);
+            func.bigint_ids_mut().into_iter().for_each(|id| *id += bigint_offset);
+        }
+        Opcode::Directive(Directive::ToLeRadix { a, b, .. }) => {
+            remap_expression(a, map_witness);
+            b.iter_mut().for_each(map_witness);
+        }
+        Opcode::Brillig(brillig) => {
+            for input in &mut brillig.inputs {
+                match input {
+                    BrilligInputs::Single(expr) => remap_expression(expr, map_witness),
+                    BrilligInputs::Array(exprs) => {
+                        exprs.iter_mut().for_each(|expr| remap_expression(expr, map_witness));
+                    }
+                    BrilligInputs::MemoryArray(block_id) => block_id.0 += block_offset,
+                }
+            }
+            for output in &mut brillig.outputs {
+                match output {
+                    BrilligOutputs::Simple(witness) => map_witness(witness),
+                    BrilligOutputs::Array(outputs) => {
+                        outputs.iter_mut().for_each(&mut *map_witness);
+                    }
+                }
+            }
+            if let Some(predicate) = &mut brillig.predicate {
+                remap_expression(predicate, map_witness);
+            }
+        }
+        Opcode::MemoryOp { block_id, op, predicate } => {
+            block_id.0 += block_offset;
+            for expr in [&mut op.operation, &mut op.index, &mut op.value] {
+                remap_expression(expr, map_witness);
+            }
+            if let Some(predicate) = predicate {
+                remap_expression(predicate, map_witness);
+            }
+        }
+        Opcode::MemoryInit { block_id, init } => {
+            block_id.0 += block_offset;
+            init.iter_mut().for_each(map_witness);
+        }
+        Opcode::Call { inputs, outputs, .. } => {
+            inputs.iter_mut().chain(outputs).for_each(map_witness);
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CircuitValidationError {
+    #[error("Circuit input references witness {witness} but the circuit only contains {num_vars} witnesses")]
+    InvalidInputWitness { witness: u32, num_vars: u32 },
+    #[error("Opcode {opcode_location} references witness {witness} but the circuit only contains {num_vars} witnesses")]
+    InvalidOpcodeWitness { opcode_location: OpcodeLocation, witness: u32, num_vars: u32 },
+}
+
+/// Returns all witnesses which are read or written by `opcode`.
+///
+/// This reuses [`remap_opcode_witnesses`] so that the witnesses found here are always exactly
+/// those which get rewritten when the circuit is remapped.
+fn opcode_witnesses(opcode: &Opcode) -> Vec<Witness> {
+    let mut witnesses = Vec::new();
+    let mut record_witness = |witness: &mut Witness| witnesses.push(*witness);
+    remap_opcode_witnesses(&mut opcode.clone(), &mut record_witness, 0, 0);
+    witnesses
 }
 
 impl Program {
@@ -281,11 +519,11 @@ impl PublicInputs {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeMap, BTreeSet};
 
     use super::{
         opcodes::{BlackBoxFuncCall, FunctionInput},
-        Circuit, Compression, Opcode, PublicInputs,
+        Circuit, CircuitValidationError, Compression, Opcode, OpcodeLocation, PublicInputs,
     };
     use crate::{
         circuit::{ExpressionWidth, Program},
@@ -417,6 +655,138 @@ mod tests {
         assert_eq!(program, deserialized);
     }
 
+    #[test]
+    fn validate_rejects_out_of_bounds_witness() {
+        let mut circuit = Circuit {
+            current_witness_index: 3,
+            opcodes: vec![and_opcode()],
+            public_parameters: PublicInputs(BTreeSet::from_iter(vec![Witness(1)])),
+            ..Circuit::default()
+        };
+        assert_eq!(circuit.validate(), Ok(()));
+
+        circuit.opcodes.push(range_opcode());
+        circuit.opcodes.push(Opcode::AssertZero(Witness(4).into()));
+        assert_eq!(
+            circuit.validate(),
+            Err(CircuitValidationError::InvalidOpcodeWitness {
+                opcode_location: OpcodeLocation::Acir(2),
+                witness: 4,
+                num_vars: 4
+            })
+        );
+
+        circuit.opcodes.pop();
+        circuit.return_values = PublicInputs(BTreeSet::from_iter(vec![Witness(7)]));
+        assert_eq!(
+            circuit.validate(),
+            Err(CircuitValidationError::InvalidInputWitness { witness: 7, num_vars: 4 })
+        );
+    }
+
+    #[test]
+    fn concat_shifts_witnesses_of_second_circuit() {
+        let first = Circuit {
+            current_witness_index: 3,
+            opcodes: vec![and_opcode()],
+            private_parameters: BTreeSet::from([Witness(1), Witness(2)]),
+            return_values: PublicInputs(BTreeSet::from([Witness(3)])),
+            ..Circuit::default()
+        };
+        let second = Circuit {
+            current_witness_index: 1,
+            opcodes: vec![range_opcode()],
+            public_parameters: PublicInputs(BTreeSet::from([Witness(1)])),
+            assert_messages: vec![(OpcodeLocation::Acir(0), "out of range".to_string())],
+            ..Circuit::default()
+        };
+
+        let combined = first.concat(&second, first.num_vars());
+        let expected_range = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput { witness: Witness(5), num_bits: 8 },
+        });
+        assert_eq!(combined.opcodes, vec![and_opcode(), expected_range]);
+        assert_eq!(combined.current_witness_index, 5);
+        assert_eq!(combined.private_parameters, BTreeSet::from([Witness(1), Witness(2)]));
+        assert_eq!(combined.public_parameters, PublicInputs(BTreeSet::from([Witness(5)])));
+        assert_eq!(combined.return_values, first.return_values);
+        assert_eq!(combined.get_assert_message(OpcodeLocation::Acir(1)), Some("out of range"));
+        assert_eq!(combined.validate(), Ok(()));
+    }
+
+    #[test]
+    fn concat_renumbers_bigints_of_second_circuit() {
+        // Creates BigInt `id` from the byte in `input` and writes its bytes back out to `output`
+        let bigint_round_trip = |input: Witness, output: Witness, id: u32| {
+            vec![
+                Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntFromLeBytes {
+                    inputs: vec![FunctionInput { witness: input, num_bits: 8 }],
+                    modulus: vec![7],
+                    output: id,
+                }),
+                Opcode::BlackBoxFuncCall(BlackBoxFuncCall::BigIntToLeBytes {
+                    input: id,
+                    outputs: vec![output],
+                }),
+            ]
+        };
+        let circuit = Circuit {
+            current_witness_index: 1,
+            opcodes: bigint_round_trip(Witness(0), Witness(1), 0),
+            ..Circuit::default()
+        };
+
+        let combined = circuit.concat(&circuit, circuit.num_vars());
+        let mut expected_opcodes = bigint_round_trip(Witness(0), Witness(1), 0);
+        expected_opcodes.extend(bigint_round_trip(Witness(2), Witness(3), 1));
+        assert_eq!(combined.opcodes, expected_opcodes);
+    }
+
+    #[test]
+    fn compact_witnesses_removes_gaps() {
+        // w2 * w5 - w9 = 0 with w5 also range constrained
+        let product = crate::native_types::Expression {
+            mul_terms: vec![(FieldElement::one(), Witness(2), Witness(5))],
+            linear_combinations: vec![(-FieldElement::one(), Witness(9))],
+            q_c: FieldElement::zero(),
+        };
+        let range = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput { witness: Witness(5), num_bits: 8 },
+        });
+        let circuit = Circuit {
+            current_witness_index: 9,
+            opcodes: vec![Opcode::AssertZero(product), range],
+            private_parameters: BTreeSet::from([Witness(2), Witness(5)]),
+            return_values: PublicInputs(BTreeSet::from([Witness(9)])),
+            ..Circuit::default()
+        };
+
+        let (compacted, witness_map) = circuit.compact_witnesses();
+        assert_eq!(
+            witness_map,
+            BTreeMap::from([
+                (Witness(2), Witness(0)),
+                (Witness(5), Witness(1)),
+                (Witness(9), Witness(2))
+            ])
+        );
+        assert_eq!(compacted.num_vars(), 3);
+        assert!(compacted.num_vars() < circuit.num_vars());
+        assert_eq!(compacted.private_parameters, BTreeSet::from([Witness(0), Witness(1)]));
+        assert_eq!(compacted.return_values, PublicInputs(BTreeSet::from([Witness(2)])));
+
+        let expected_product = crate::native_types::Expression {
+            mul_terms: vec![(FieldElement::one(), Witness(0), Witness(1))],
+            linear_combinations: vec![(-FieldElement::one(), Witness(2))],
+            q_c: FieldElement::zero(),
+        };
+        let expected_range = Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput { witness: Witness(1), num_bits: 8 },
+        });
+        assert_eq!(compacted.opcodes, vec![Opcode::AssertZero(expected_product), expected_range]);
+        assert_eq!(compacted.validate(), Ok(()));
+    }
+
     #[test]
     fn does_not_panic_on_invalid_circuit() {
         use std::io::Write;
@@ -338,6 +338,142 @@ impl BlackBoxFuncCall {
             BlackBoxFuncCall::BigIntToLeBytes { outputs, .. } => outputs.to_vec(),
         }
     }
+
+    /// Returns mutable references to every witness which is read or written by this call.
+    pub(crate) fn witnesses_mut(&mut self) -> Vec<&mut Witness> {
+        fn input_witnesses(inputs: &mut [FunctionInput]) -> impl Iterator<Item = &mut Witness> {
+            inputs.iter_mut().map(|input| &mut input.witness)
+        }
+
+        match self {
+            BlackBoxFuncCall::AND { lhs, rhs, output }
+            | BlackBoxFuncCall::XOR { lhs, rhs, output } => {
+                vec![&mut lhs.witness, &mut rhs.witness, output]
+            }
+            BlackBoxFuncCall::RANGE { input } => vec![&mut input.witness],
+            BlackBoxFuncCall::SHA256 { inputs, outputs }
+            | BlackBoxFuncCall::Blake2s { inputs, outputs }
+            | BlackBoxFuncCall::Blake3 { inputs, outputs }
+            | BlackBoxFuncCall::Keccak256 { inputs, outputs }
+            | BlackBoxFuncCall::Keccakf1600 { inputs, outputs }
+            | BlackBoxFuncCall::Poseidon2Permutation { inputs, outputs, .. } => {
+                input_witnesses(inputs).chain(outputs.iter_mut()).collect()
+            }
+            BlackBoxFuncCall::Keccak256VariableLength { inputs, var_message_size, outputs } => {
+                input_witnesses(inputs)
+                    .chain(std::iter::once(&mut var_message_size.witness))
+                    .chain(outputs.iter_mut())
+                    .collect()
+            }
+            BlackBoxFuncCall::Sha256Compression { inputs, hash_values, outputs } => {
+                input_witnesses(inputs)
+                    .chain(input_witnesses(hash_values))
+                    .chain(outputs.iter_mut())
+                    .collect()
+            }
+            BlackBoxFuncCall::SchnorrVerify {
+                public_key_x,
+                public_key_y,
+                signature,
+                message,
+                output,
+            } => [&mut public_key_x.witness, &mut public_key_y.witness]
+                .into_iter()
+                .chain(input_witnesses(signature))
+                .chain(input_witnesses(message))
+                .chain(std::iter::once(output))
+                .collect(),
+            BlackBoxFuncCall::PedersenCommitment { inputs, outputs, .. } => {
+                input_witnesses(inputs).chain([&mut outputs.0, &mut outputs.1]).collect()
+            }
+            BlackBoxFuncCall::PedersenHash { inputs, output, .. } => {
+                input_witnesses(inputs).chain(std::iter::once(output)).collect()
+            }
+            BlackBoxFuncCall::EcdsaSecp256k1 {
+                public_key_x,
+                public_key_y,
+                signature,
+                hashed_message,
+                output,
+            }
+            | BlackBoxFuncCall::EcdsaSecp256r1 {
+                public_key_x,
+                public_key_y,
+                signature,
+                hashed_message,
+                output,
+            } => input_witnesses(public_key_x)
+                .chain(input_witnesses(public_key_y))
+                .chain(input_witnesses(signature))
+                .chain(input_witnesses(hashed_message))
+                .chain(std::iter::once(output))
+                .collect(),
+            BlackBoxFuncCall::FixedBaseScalarMul { low, high, outputs } => {
+                vec![&mut low.witness, &mut high.witness, &mut outputs.0, &mut outputs.1]
+            }
+            BlackBoxFuncCall::EmbeddedCurveAdd {
+                input1_x,
+                input1_y,
+                input2_x,
+                input2_y,
+                outputs,
+            } => vec![
+                &mut input1_x.witness,
+                &mut input1_y.witness,
+                &mut input2_x.witness,
+                &mut input2_y.witness,
+                &mut outputs.0,
+                &mut outputs.1,
+            ],
+            BlackBoxFuncCall::RecursiveAggregation {
+                verification_key,
+                proof,
+                public_inputs,
+                key_hash,
+            } => input_witnesses(verification_key)
+                .chain(input_witnesses(proof))
+                .chain(input_witnesses(public_inputs))
+                .chain(std::iter::once(&mut key_hash.witness))
+                .collect(),
+            BlackBoxFuncCall::BigIntFromLeBytes { inputs, .. } => input_witnesses(inputs).collect(),
+            BlackBoxFuncCall::BigIntToLeBytes { outputs, .. } => outputs.iter_mut().collect(),
+            BlackBoxFuncCall::BigIntAdd { .. }
+            | BlackBoxFuncCall::BigIntSub { .. }
+            | BlackBoxFuncCall::BigIntMul { .. }
+            | BlackBoxFuncCall::BigIntDiv { .. } => Vec::new(),
+        }
+    }
+
+    /// Returns mutable references to every BigInt id which is read or written by this call.
+    pub(crate) fn bigint_ids_mut(&mut self) -> Vec<&mut u32> {
+        match self {
+            BlackBoxFuncCall::BigIntAdd { lhs, rhs, output }
+            | BlackBoxFuncCall::BigIntSub { lhs, rhs, output }
+            | BlackBoxFuncCall::BigIntMul { lhs, rhs, output }
+            | BlackBoxFuncCall::BigIntDiv { lhs, rhs, output } => vec![lhs, rhs, output],
+            BlackBoxFuncCall::BigIntFromLeBytes { output, .. } => vec![output],
+            BlackBoxFuncCall::BigIntToLeBytes { input, .. } => vec![input],
+            BlackBoxFuncCall::AND { .. }
+            | BlackBoxFuncCall::XOR { .. }
+            | BlackBoxFuncCall::RANGE { .. }
+            | BlackBoxFuncCall::SHA256 { .. }
+            | BlackBoxFuncCall::Blake2s { .. }
+            | BlackBoxFuncCall::Blake3 { .. }
+            | BlackBoxFuncCall::SchnorrVerify { .. }
+            | BlackBoxFuncCall::PedersenCommitment { .. }
+            | BlackBoxFuncCall::PedersenHash { .. }
+            | BlackBoxFuncCall::EcdsaSecp256k1 { .. }
+            | BlackBoxFuncCall::EcdsaSecp256r1 { .. }
+            | BlackBoxFuncCall::FixedBaseScalarMul { .. }
+            | BlackBoxFuncCall::EmbeddedCurveAdd { .. }
+            | BlackBoxFuncCall::Keccak256 { .. }
+            | BlackBoxFuncCall::Keccak256VariableLength { .. }
+            | BlackBoxFuncCall::Keccakf1600 { .. }
+            | BlackBoxFuncCall::RecursiveAggregation { .. }
+            | BlackBoxFuncCall::Poseidon2Permutation { .. }
+            | BlackBoxFuncCall::Sha256Compression { .. } => Vec::new(),
+        }
+    }
 }
 
 const ABBREVIATION_LIMIT: usize = 5;
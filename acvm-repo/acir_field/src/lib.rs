@@ -64,3 +64,19 @@ macro_rules! assert_unique_feature {
 // https://internals.rust-lang.org/t/mutually-exclusive-feature-flags/8601/7
 // If another field/feature is added, we add it here too
 assert_unique_feature!("bn254", "bls12_381");
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldElement, FieldOptions, CHOSEN_FIELD};
+
+    #[test]
+    fn max_num_bits_matches_chosen_field() {
+        let expected_num_bits = match CHOSEN_FIELD {
+            FieldOptions::BN254 => 254,
+            FieldOptions::BLS12_381 => 255,
+        };
+        assert_eq!(FieldElement::max_num_bits(), expected_num_bits);
+        assert!(FieldOptions::is_native_field(CHOSEN_FIELD.to_string()));
+        assert!(FieldOptions::is_native_field(&FieldElement::modulus().to_string()));
+    }
+}
@@ -40,7 +40,11 @@ fn simplify_mul_terms(mut gate: Expression) -> Expression {
         *hash_map.entry((pair[0], pair[1])).or_insert_with(FieldElement::zero) += scale;
     }
 
-    gate.mul_terms = hash_map.into_iter().map(|((w_l, w_r), scale)| (scale, w_l, w_r)).collect();
+    gate.mul_terms = hash_map
+        .into_iter()
+        .filter(|(_, scale)| scale != &FieldElement::zero())
+        .map(|((w_l, w_r), scale)| (scale, w_l, w_r))
+        .collect();
     gate
 }
 
@@ -60,3 +64,59 @@ fn simplify_linear_terms(mut gate: Expression) -> Expression {
         .collect();
     gate
 }
+
+#[cfg(test)]
+mod tests {
+    use acir::{
+        native_types::{Expression, Witness},
+        FieldElement,
+    };
+
+    use super::GeneralOptimizer;
+
+    #[test]
+    fn removes_zero_coefficient_terms() {
+        // 0 * w1 * w2 + 2 * w1 + 0 * w3 + 1 = 0
+        let expr = Expression {
+            mul_terms: vec![(FieldElement::zero(), Witness(1), Witness(2))],
+            linear_combinations: vec![
+                (FieldElement::from(2u128), Witness(1)),
+                (FieldElement::zero(), Witness(3)),
+            ],
+            q_c: FieldElement::one(),
+        };
+
+        let expected = Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: vec![(FieldElement::from(2u128), Witness(1))],
+            q_c: FieldElement::one(),
+        };
+        assert_eq!(GeneralOptimizer::optimize(expr), expected);
+    }
+
+    #[test]
+    fn merges_and_cancels_duplicate_terms() {
+        // w1 * w2 - w2 * w1 + 3 * w1 * w1 + w3 - w3 + w4 + w4 = 0
+        let expr = Expression {
+            mul_terms: vec![
+                (FieldElement::one(), Witness(1), Witness(2)),
+                (-FieldElement::one(), Witness(2), Witness(1)),
+                (FieldElement::from(3u128), Witness(1), Witness(1)),
+            ],
+            linear_combinations: vec![
+                (FieldElement::one(), Witness(3)),
+                (-FieldElement::one(), Witness(3)),
+                (FieldElement::one(), Witness(4)),
+                (FieldElement::one(), Witness(4)),
+            ],
+            q_c: FieldElement::zero(),
+        };
+
+        let expected = Expression {
+            mul_terms: vec![(FieldElement::from(3u128), Witness(1), Witness(1))],
+            linear_combinations: vec![(FieldElement::from(2u128), Witness(4))],
+            q_c: FieldElement::zero(),
+        };
+        assert_eq!(GeneralOptimizer::optimize(expr), expected);
+    }
+}
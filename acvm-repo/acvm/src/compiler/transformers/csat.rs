@@ -507,3 +507,54 @@ fn stepwise_reduction_test() {
     let contains_b = got_optimized_opcode_a.linear_combinations.iter().any(|(_, w)| *w == b);
     assert!(contains_b);
 }
+
+#[test]
+fn wide_opcode_is_split_into_width_sized_opcodes() {
+    let a = Witness(0);
+    let b = Witness(1);
+    let c = Witness(2);
+    let d = Witness(3);
+
+    // a*b + a + b + c + d = 0 touches four witnesses, one more than a width 3 gate can hold.
+    let opcode = Expression {
+        mul_terms: vec![(FieldElement::one(), a, b)],
+        linear_combinations: vec![
+            (FieldElement::one(), a),
+            (FieldElement::one(), b),
+            (FieldElement::one(), c),
+            (FieldElement::one(), d),
+        ],
+        q_c: FieldElement::zero(),
+    };
+    assert!(!opcode.fits_in_one_identity(3));
+
+    let mut intermediate_variables: IndexMap<Expression, (FieldElement, Witness)> = IndexMap::new();
+    let mut num_witness = 4;
+
+    let mut optimizer = CSatTransformer::new(3);
+    for witness in [a, b, c, d] {
+        optimizer.mark_solvable(witness);
+    }
+    let got_optimized_opcode =
+        optimizer.transform(opcode, &mut intermediate_variables, &mut num_witness);
+
+    // The wires which do not fit must be moved into intermediate opcodes rather than dropped.
+    assert!(got_optimized_opcode.fits_in_one_identity(3));
+    assert!(!intermediate_variables.is_empty());
+    for (intermediate_opcode, (_, witness)) in &intermediate_variables {
+        let mut defining_opcode = intermediate_opcode.clone();
+        defining_opcode.linear_combinations.push((-FieldElement::one(), *witness));
+        assert!(defining_opcode.fits_in_one_identity(3));
+    }
+
+    // Every original wire must still be constrained by one of the resulting opcodes.
+    let opcodes = std::iter::once(&got_optimized_opcode).chain(intermediate_variables.keys());
+    let mut witnesses = HashSet::new();
+    for opcode in opcodes {
+        witnesses.extend(opcode.linear_combinations.iter().map(|(_, w)| *w));
+        witnesses.extend(opcode.mul_terms.iter().flat_map(|(_, l, r)| [*l, *r]));
+    }
+    for witness in [a, b, c, d] {
+        assert!(witnesses.contains(&witness));
+    }
+}
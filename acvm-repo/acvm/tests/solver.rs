@@ -1,16 +1,18 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use acir::{
     brillig::{BinaryFieldOp, MemoryAddress, Opcode as BrilligOpcode, Value, ValueOrArray},
     circuit::{
         brillig::{Brillig, BrilligInputs, BrilligOutputs},
-        opcodes::{BlockId, MemOp},
-        Opcode, OpcodeLocation,
+        directives::Directive,
+        opcodes::{BlackBoxFuncCall, BlockId, FunctionInput, MemOp},
+        Circuit, ExpressionWidth, Opcode, OpcodeLocation,
     },
     native_types::{Expression, Witness, WitnessMap},
     FieldElement,
 };
 
+use acvm::compiler::compile;
 use acvm::pwg::{ACVMStatus, ErrorLocation, ForeignCallWaitInfo, OpcodeResolutionError, ACVM};
 use acvm_blackbox_solver::StubbedBlackBoxSolver;
 use brillig_vm::brillig::HeapValueType;
@@ -648,3 +650,127 @@ fn memory_operations() {
 
     assert_eq!(witness_map[&Witness(8)], FieldElement::from(6u128));
 }
+
+#[test]
+fn compacted_circuit_is_satisfiable() {
+    // w3 * w7 - w12 = 0 and w12 - w20 = 5
+    let opcodes = vec![
+        Opcode::AssertZero(Expression {
+            mul_terms: vec![(FieldElement::one(), Witness(3), Witness(7))],
+            linear_combinations: vec![(-FieldElement::one(), Witness(12))],
+            q_c: FieldElement::zero(),
+        }),
+        Opcode::AssertZero(Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: vec![
+                (FieldElement::one(), Witness(12)),
+                (-FieldElement::one(), Witness(20)),
+            ],
+            q_c: -FieldElement::from(5u128),
+        }),
+    ];
+    let circuit = Circuit { current_witness_index: 20, opcodes, ..Circuit::default() };
+
+    let (compacted, witness_map) = circuit.compact_witnesses();
+    assert_eq!(compacted.num_vars(), 4);
+
+    let initial_witness = WitnessMap::from(BTreeMap::from([
+        (witness_map[&Witness(3)], FieldElement::from(2u128)),
+        (witness_map[&Witness(7)], FieldElement::from(4u128)),
+    ]));
+    let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &compacted.opcodes, initial_witness);
+    assert_eq!(acvm.solve(), ACVMStatus::Solved);
+    let solved_witness = acvm.finalize();
+
+    assert_eq!(solved_witness[&witness_map[&Witness(12)]], FieldElement::from(8u128));
+    assert_eq!(solved_witness[&witness_map[&Witness(20)]], FieldElement::from(3u128));
+}
+
+#[test]
+fn wide_linear_opcode_is_split_to_fit_width() {
+    // w1 + w2 + w3 + w4 + w5 - w6 = 0
+    let inputs: Vec<Witness> = (1..=5).map(Witness).collect();
+    let sum = Witness(6);
+    let mut linear_combinations: Vec<_> =
+        inputs.iter().map(|witness| (FieldElement::one(), *witness)).collect();
+    linear_combinations.push((-FieldElement::one(), sum));
+    let opcodes = vec![Opcode::AssertZero(Expression {
+        mul_terms: Vec::new(),
+        linear_combinations,
+        q_c: FieldElement::zero(),
+    })];
+    let circuit = Circuit {
+        current_witness_index: 6,
+        opcodes,
+        private_parameters: BTreeSet::from_iter(inputs.iter().copied()),
+        ..Circuit::default()
+    };
+
+    let (circuit, _) = compile(circuit, ExpressionWidth::Bounded { width: 3 });
+    assert!(circuit.opcodes.len() > 1);
+    for opcode in &circuit.opcodes {
+        let Opcode::AssertZero(expr) = opcode else {
+            panic!("expected only AssertZero opcodes, found {opcode:?}");
+        };
+        assert!(expr.fits_in_one_identity(3), "{expr:?} does not fit in a width 3 gate");
+    }
+
+    let initial_witness = WitnessMap::from(BTreeMap::from_iter(
+        inputs.iter().zip(1u128..).map(|(witness, value)| (*witness, FieldElement::from(value))),
+    ));
+    let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &circuit.opcodes, initial_witness);
+    assert_eq!(acvm.solve(), ACVMStatus::Solved);
+    let solved_witness = acvm.finalize();
+
+    assert_eq!(solved_witness[&sum], FieldElement::from(15u128));
+}
+
+/// Builds the opcodes which range constrain `input` to an odd number of bits by splitting it
+/// into its top bit and the remaining even number of lower bits.
+fn odd_range_opcodes(input: Witness, num_bits: u32, low: Witness, top: Witness) -> Vec<Opcode> {
+    let range = |witness, num_bits| {
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput { witness, num_bits },
+        })
+    };
+    // input - 2^(num_bits - 1) * top - low = 0
+    let recomposition = Opcode::AssertZero(Expression {
+        mul_terms: Vec::new(),
+        linear_combinations: vec![
+            (FieldElement::one(), input),
+            (-FieldElement::from(1u128 << (num_bits - 1)), top),
+            (-FieldElement::one(), low),
+        ],
+        q_c: FieldElement::zero(),
+    });
+    vec![
+        Opcode::Directive(Directive::ToLeRadix {
+            a: input.into(),
+            b: vec![low, top],
+            radix: 1 << (num_bits - 1),
+        }),
+        range(low, num_bits - 1),
+        range(top, 1),
+        recomposition,
+    ]
+}
+
+#[test]
+fn odd_range_decomposition_is_solved() {
+    let (input, low, top) = (Witness(1), Witness(2), Witness(3));
+    let opcodes = odd_range_opcodes(input, 7, low, top);
+
+    // 0b1011010 = 90 = 64 + 26
+    let initial_witness = WitnessMap::from(BTreeMap::from([(input, FieldElement::from(90u128))]));
+    let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &opcodes, initial_witness);
+    assert_eq!(acvm.solve(), ACVMStatus::Solved);
+    let witness_map = acvm.finalize();
+
+    assert_eq!(witness_map[&top], FieldElement::one());
+    assert_eq!(witness_map[&low], FieldElement::from(26u128));
+
+    // 128 does not fit in 7 bits, so its top "bit" is 2 and fails its range constraint.
+    let initial_witness = WitnessMap::from(BTreeMap::from([(input, FieldElement::from(128u128))]));
+    let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &opcodes, initial_witness);
+    assert!(matches!(acvm.solve(), ACVMStatus::Failure(_)));
+}
@@ -124,3 +124,20 @@ mod keccakf1600_tests {
         assert_eq!(state_second, expected_state_second);
     }
 }
+
+#[cfg(test)]
+mod blake3_tests {
+    use super::blake3;
+
+    #[test]
+    fn known_answer() {
+        // blake3("hello"), see https://connor4312.github.io/blake3/index.html
+        let expected = [
+            0xea, 0x8f, 0x16, 0x3d, 0xb3, 0x86, 0x82, 0x92, 0x5e, 0x44, 0x91, 0xc5, 0xe5, 0x8d,
+            0x4b, 0xb3, 0x50, 0x6e, 0xf8, 0xc1, 0x4e, 0xb7, 0x8a, 0x86, 0xe9, 0x08, 0xc5, 0x62,
+            0x4a, 0x67, 0x20, 0x0f,
+        ];
+
+        assert_eq!(blake3(b"hello").unwrap(), expected);
+    }
+}
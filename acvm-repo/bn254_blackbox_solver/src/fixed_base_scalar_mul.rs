@@ -98,6 +98,22 @@ mod grumpkin_fixed_base_scalar_mul {
         Ok(())
     }
 
+    #[test]
+    fn small_scalar_matches_point_addition() -> Result<(), BlackBoxResolutionError> {
+        let generator = fixed_base_scalar_mul(&FieldElement::one(), &FieldElement::zero())?;
+
+        let res = fixed_base_scalar_mul(&FieldElement::from(2u128), &FieldElement::zero())?;
+        let x = "06ce1b0827aafa85ddeb49cdaa36306d19a74caa311e13d46d8bc688cdbffffe";
+        let y = "1c122f81a3a14964909ede0ba2a6855fc93faf6fa1a788bf467be7e7a43f80ac";
+
+        assert_eq!(x, res.0.to_hex());
+        assert_eq!(y, res.1.to_hex());
+
+        let doubled = embedded_curve_add(generator.0, generator.1, generator.0, generator.1)?;
+        assert_eq!(doubled, res);
+        Ok(())
+    }
+
     #[test]
     fn rejects_invalid_limbs() {
         let max_limb = FieldElement::from(u128::MAX);
@@ -71,3 +71,18 @@ fn pedersen_hash_to_point() -> Result<(), Error> {
     assert_eq!(expected_y.to_hex(), y.to_hex());
     Ok(())
 }
+
+#[test]
+fn hash_index_changes_output() -> Result<(), Error> {
+    let barretenberg = Barretenberg::new();
+    let inputs = vec![FieldElement::one(), FieldElement::from(2u128)];
+
+    let default_hash = barretenberg.hash(inputs.clone(), 0)?;
+    let separated_hash = barretenberg.hash(inputs.clone(), 1)?;
+    assert_ne!(default_hash, separated_hash);
+
+    let default_commitment = barretenberg.encrypt(inputs.clone(), 0)?;
+    let separated_commitment = barretenberg.encrypt(inputs, 1)?;
+    assert_ne!(default_commitment, separated_commitment);
+    Ok(())
+}
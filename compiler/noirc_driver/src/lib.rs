@@ -3,7 +3,7 @@
 #![warn(unreachable_pub)]
 #![warn(clippy::semicolon_if_nothing_returned)]
 
-use acvm::acir::circuit::{ExpressionWidth, Program};
+use acvm::acir::circuit::{Circuit, ExpressionWidth, Program};
 use clap::Args;
 use fm::{FileId, FileManager};
 use iter_extended::vecmap;
@@ -13,13 +13,14 @@ use noirc_evaluator::create_circuit;
 use noirc_evaluator::errors::RuntimeError;
 use noirc_frontend::debug::build_debug_crate_file;
 use noirc_frontend::graph::{CrateId, CrateName};
-use noirc_frontend::hir::def_map::{Contract, CrateDefMap};
+use noirc_frontend::hir::def_map::{parse_file, Contract, CrateDefMap};
 use noirc_frontend::hir::Context;
 use noirc_frontend::macros_api::MacroProcessor;
 use noirc_frontend::monomorphization::{monomorphize, monomorphize_debug, MonomorphizationError};
 use noirc_frontend::node_interner::FuncId;
 use noirc_frontend::token::SecondaryAttribute;
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::info;
 
@@ -47,6 +48,9 @@ pub const NOIRC_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const NOIR_ARTIFACT_VERSION_STRING: &str =
     concat!(env!("CARGO_PKG_VERSION"), "+", env!("GIT_COMMIT"));
 
+/// The number of times a loop may be unrolled when `--max-loop-iterations` isn't passed.
+pub const DEFAULT_MAX_LOOP_ITERATIONS: usize = 1_000_000;
+
 #[derive(Args, Clone, Debug, Default)]
 pub struct CompileOptions {
     /// Override the expression width requested by the backend.
@@ -95,6 +99,21 @@ pub struct CompileOptions {
     /// Force Brillig output (for step debugging)
     #[arg(long, hide = true)]
     pub force_brillig: bool,
+
+    /// Fail compilation if the circuit contains more than this many ACIR opcodes once it has been
+    /// optimized for the expression width
+    #[arg(long)]
+    pub max_opcodes: Option<usize>,
+
+    /// Fail compilation if a single loop would be unrolled more than this many times. The limit
+    /// applies to each loop separately rather than to the total across all loops
+    /// [default: 1000000]
+    #[arg(long)]
+    pub max_loop_iterations: Option<usize>,
+
+    /// Warn about witnesses which are assigned by unconstrained code but never constrained
+    #[arg(long)]
+    pub warn_unconstrained_witnesses: bool,
 }
 
 fn parse_expression_width(input: &str) -> Result<ExpressionWidth, std::io::Error> {
@@ -304,6 +323,99 @@ pub fn compile_main(
     Ok((compiled_program, warnings))
 }
 
+/// Compiles the program whose entry point is `root_file` using only the in-memory `sources`,
+/// without reading anything from the filesystem.
+///
+/// All paths are relative to the root of the program and the stdlib is added automatically.
+pub fn compile_sources(
+    sources: BTreeMap<PathBuf, String>,
+    root_file: &Path,
+    options: &CompileOptions,
+) -> CompilationResult<CompiledProgram> {
+    let (mut context, crate_id) = prepare_sources(sources, root_file)?;
+    compile_main(&mut context, crate_id, options, None)
+}
+
+/// Creates a [Context] holding the in-memory `sources` and prepares the crate whose entry point
+/// is `root_file`, as done by [compile_sources].
+pub fn prepare_sources(
+    sources: BTreeMap<PathBuf, String>,
+    root_file: &Path,
+) -> Result<(Context<'static, 'static>, CrateId), Vec<FileDiagnostic>> {
+    let mut file_manager = file_manager_with_stdlib(Path::new(""));
+    for (path, source) in sources {
+        file_manager.add_file_with_source(&path, source);
+    }
+
+    if file_manager.name_to_id(root_file.to_path_buf()).is_none() {
+        let err = CustomDiagnostic::from_message(&format!(
+            "root file {} is not one of the provided sources",
+            root_file.display()
+        ))
+        .in_file(FileId::default());
+        return Err(vec![err]);
+    }
+
+    let parsed_files = file_manager
+        .as_file_map()
+        .all_file_ids()
+        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+        .collect();
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let crate_id = prepare_crate(&mut context, root_file);
+    Ok((context, crate_id))
+}
+
+/// Compiles each `.nr` file directly within `bin_dir` as a separate program, using only the
+/// in-memory `sources`.
+///
+/// Programs are named after the file stem of their entry point so `bin/foo.nr` is compiled as
+/// `foo`. The remaining sources can be used by every program, e.g. as modules of its entry point.
+pub fn compile_binaries(
+    sources: BTreeMap<PathBuf, String>,
+    bin_dir: &Path,
+    options: &CompileOptions,
+) -> CompilationResult<BTreeMap<String, CompiledProgram>> {
+    let entry_points: Vec<PathBuf> = sources
+        .keys()
+        .filter(|path| path.parent() == Some(bin_dir))
+        .filter(|path| path.extension().map_or(false, |extension| extension == "nr"))
+        .cloned()
+        .collect();
+
+    if entry_points.is_empty() {
+        let message = format!("no binaries found in {}", bin_dir.display());
+        return Err(vec![CustomDiagnostic::from_message(&message).in_file(FileId::default())]);
+    }
+
+    let mut programs = BTreeMap::new();
+    let mut warnings = Vec::new();
+    let mut errors = Vec::new();
+    for entry_point in entry_points {
+        let name = entry_point
+            .file_stem()
+            .expect("entry points should have a file name")
+            .to_string_lossy()
+            .into_owned();
+
+        match compile_sources(sources.clone(), &entry_point, options) {
+            Ok((program, program_warnings)) => {
+                programs.insert(name, program);
+                warnings.extend(program_warnings);
+            }
+            Err(program_errors) => errors.extend(program_errors),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok((programs, warnings))
+    } else {
+        errors.extend(warnings);
+        Err(errors)
+    }
+}
+
 /// Run the frontend to check the crate for errors then compile all contracts if there were none
 pub fn compile_contract(
     context: &mut Context,
@@ -475,11 +587,20 @@ pub fn compile_no_check(
 
     if !force_compile && hashes_match {
         info!("Program matches existing artifact, returning early");
-        return Ok(cached_program.expect("cache must exist for hashes to match"));
+        let cached_program = cached_program.expect("cache must exist for hashes to match");
+        check_opcode_limit(context, options, main_function, &cached_program.program.functions[0])?;
+        return Ok(cached_program);
     }
     let visibility = program.return_visibility;
-    let (circuit, debug, input_witnesses, return_witnesses, warnings) =
-        create_circuit(program, options.show_ssa, options.show_brillig, options.force_brillig)?;
+    let (circuit, debug, input_witnesses, return_witnesses, warnings) = create_circuit(
+        program,
+        options.show_ssa,
+        options.show_brillig,
+        options.force_brillig,
+        options.max_loop_iterations.unwrap_or(DEFAULT_MAX_LOOP_ITERATIONS),
+        options.warn_unconstrained_witnesses,
+    )?;
+    check_opcode_limit(context, options, main_function, &circuit)?;
 
     let abi =
         abi_gen::gen_abi(context, &main_function, input_witnesses, return_witnesses, visibility);
@@ -496,3 +617,30 @@ pub fn compile_no_check(
         warnings,
     })
 }
+
+/// Returns an error if `circuit` contains more opcodes than allowed by [`CompileOptions::max_opcodes`].
+///
+/// Opcodes are counted after the circuit has been optimized for the expression width given in
+/// [`CompileOptions::expression_width`], as this is the circuit which is passed to the backend.
+/// Splitting wide expressions to fit a bounded width can add opcodes. nargo sets this to the
+/// backend's width before compiling. Without a width the circuit is counted as it is returned.
+fn check_opcode_limit(
+    context: &Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+    circuit: &Circuit,
+) -> Result<(), RuntimeError> {
+    let Some(limit) = options.max_opcodes else {
+        return Ok(());
+    };
+
+    let expression_width = options.expression_width.unwrap_or(ExpressionWidth::Unbounded);
+    let (optimized_circuit, _) = acvm::compiler::compile(circuit.clone(), expression_width);
+    let num_opcodes = optimized_circuit.opcodes.len();
+    if num_opcodes > limit {
+        let location = context.def_interner.function_meta(&main_function).name.location;
+        let call_stack = [location].into_iter().collect();
+        return Err(RuntimeError::OpcodeLimitExceeded { num_opcodes, limit, call_stack });
+    }
+    Ok(())
+}
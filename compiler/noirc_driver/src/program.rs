@@ -28,3 +28,17 @@ pub struct CompiledProgram {
     pub file_map: BTreeMap<FileId, DebugFile>,
     pub warnings: Vec<SsaReport>,
 }
+
+impl CompiledProgram {
+    /// Returns the number of witnesses which hold the inputs to the program's `main` function.
+    pub fn num_input_witnesses(&self) -> usize {
+        self.program.functions[0].circuit_arguments().len()
+    }
+
+    /// Returns the number of witnesses introduced by the compiler to hold intermediate values,
+    /// i.e. every witness in the circuit which is not an input to `main`.
+    pub fn num_intermediate_witnesses(&self) -> usize {
+        let num_witnesses = self.program.functions[0].num_vars() as usize;
+        num_witnesses.saturating_sub(self.num_input_witnesses())
+    }
+}
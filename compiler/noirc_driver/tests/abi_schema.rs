@@ -0,0 +1,131 @@
+mod common;
+
+use acvm::{acir::circuit::ExpressionWidth, compiler::compile as compile_acir};
+use noirc_abi::{AbiParameter, AbiType, AbiVisibility, Sign};
+use noirc_driver::CompileOptions;
+
+use common::compile_main;
+
+#[test]
+fn abi_describes_parameter_types_and_visibility() {
+    let source = "
+struct Point {
+    x: Field,
+    y: i16,
+}
+
+fn main(a: u8, b: pub [i32; 2], c: (bool, Field), d: Point) -> pub Field {
+    assert(a as i32 != b[0]);
+    assert(c.0);
+    d.x + c.1
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    let abi = program.abi;
+
+    let private = AbiVisibility::Private;
+    let public = AbiVisibility::Public;
+    let parameter =
+        |name: &str, typ, visibility| AbiParameter { name: name.to_string(), typ, visibility };
+    assert_eq!(
+        &abi.parameters[0..3],
+        &[
+            parameter("a", AbiType::Integer { sign: Sign::Unsigned, width: 8 }, private),
+            parameter(
+                "b",
+                AbiType::Array {
+                    length: 2,
+                    typ: Box::new(AbiType::Integer { sign: Sign::Signed, width: 32 })
+                },
+                public
+            ),
+            parameter(
+                "c",
+                AbiType::Tuple { fields: vec![AbiType::Boolean, AbiType::Field] },
+                private
+            ),
+        ]
+    );
+
+    let AbiType::Struct { fields, .. } = &abi.parameters[3].typ else {
+        panic!("Expected `d` to have a struct type");
+    };
+    assert_eq!(
+        fields,
+        &[
+            ("x".to_string(), AbiType::Field),
+            ("y".to_string(), AbiType::Integer { sign: Sign::Signed, width: 16 })
+        ]
+    );
+
+    let return_type = abi.return_type.as_ref().expect("main should have a return type");
+    assert_eq!(return_type.abi_type, AbiType::Field);
+    assert_eq!(return_type.visibility, public);
+
+    // The schema can be passed to other toolchains as JSON.
+    let json = serde_json::to_value(&abi).expect("ABI should serialize to JSON");
+    assert_eq!(json["parameters"][0]["name"], "a");
+    assert_eq!(json["parameters"][0]["type"]["kind"], "integer");
+    assert_eq!(json["parameters"][0]["type"]["sign"], "unsigned");
+    assert_eq!(json["parameters"][0]["visibility"], "private");
+    assert_eq!(json["parameters"][1]["type"]["type"]["sign"], "signed");
+    assert_eq!(json["parameters"][1]["visibility"], "public");
+}
+
+#[test]
+fn public_inputs_follow_abi_parameter_order() {
+    let source = "
+fn main(b: pub Field, hidden: Field, a: pub [Field; 2]) {
+    assert(b + hidden + a[0] + a[1] != 0);
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    let abi = &program.abi;
+
+    // The witness indices of each public parameter, in the order the ABI declares them.
+    let expected: Vec<u32> = abi
+        .parameters
+        .iter()
+        .filter(|param| param.is_public())
+        .flat_map(|param| abi.param_witnesses[&param.name].clone())
+        .flat_map(|range| range.start.witness_index()..range.end.witness_index())
+        .collect();
+    assert_eq!(expected.len(), 3);
+
+    let circuit = &program.program.functions[0];
+    assert_eq!(circuit.public_parameters.indices(), expected);
+
+    // Backend specific transformations add new witnesses but must not reorder the public inputs.
+    let (circuit, _) = compile_acir(circuit.clone(), ExpressionWidth::Bounded { width: 3 });
+    assert_eq!(circuit.public_parameters.indices(), expected);
+}
+
+#[test]
+fn abi_reports_type_of_each_input_witness() {
+    let source = "
+fn main(x: u32, y: [bool; 2], z: Field) {
+    assert(y[0] | y[1]);
+    assert(x as Field != z);
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    let abi = program.abi;
+
+    let witness_types = abi.input_witness_types();
+    let types: Vec<_> = witness_types.iter().map(|(_, typ)| typ.clone()).collect();
+    assert_eq!(
+        types,
+        [
+            AbiType::Integer { sign: Sign::Unsigned, width: 32 },
+            AbiType::Boolean,
+            AbiType::Boolean,
+            AbiType::Field,
+        ]
+    );
+
+    let x_witness = abi.param_witnesses["x"][0].start;
+    assert_eq!(witness_types[0].0, x_witness);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use noirc_driver::CompilationResult;
+
+use common::prepare_main;
+
+fn check(source: &str) -> CompilationResult<()> {
+    let (mut context, crate_id) = prepare_main(source);
+
+    // Only runs name resolution and type checking, no circuit is generated.
+    noirc_driver::check_crate(&mut context, crate_id, false, false)
+}
+
+#[test]
+fn check_crate_accepts_valid_program() {
+    let source = "
+fn main(x: Field, y: pub Field) {
+    assert(x != y);
+}";
+
+    let ((), warnings) = check(source).expect("program should type check");
+    assert_eq!(warnings, Vec::new());
+}
+
+#[test]
+fn check_crate_reports_type_errors() {
+    let source = "
+fn main(x: Field, y: u8) {
+    assert(x == y);
+}";
+
+    let errors = check(source).expect_err("program should fail to type check");
+    assert_eq!(errors.len(), 1, "expected 1 error, got: {errors:?}");
+    assert!(errors[0].diagnostic.is_error());
+}
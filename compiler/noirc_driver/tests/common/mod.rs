@@ -0,0 +1,31 @@
+//! Helpers for compiling single file programs held in memory.
+
+// Each test binary includes this module but only uses some of its helpers.
+#![allow(dead_code)]
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use noirc_driver::{
+    compile_sources, prepare_sources, CompilationResult, CompileOptions, CompiledProgram,
+};
+use noirc_frontend::{graph::CrateId, hir::Context};
+
+const MAIN_FILE: &str = "main.nr";
+
+fn main_sources(source: &str) -> BTreeMap<PathBuf, String> {
+    BTreeMap::from([(PathBuf::from(MAIN_FILE), source.to_string())])
+}
+
+/// Compiles `source` as the `main.nr` file of a binary crate.
+pub fn compile_main(source: &str, options: &CompileOptions) -> CompilationResult<CompiledProgram> {
+    compile_sources(main_sources(source), Path::new(MAIN_FILE), options)
+}
+
+/// Prepares a [Context] holding `source` as the `main.nr` file of a crate without compiling it.
+pub fn prepare_main(source: &str) -> (Context<'static, 'static>, CrateId) {
+    prepare_sources(main_sources(source), Path::new(MAIN_FILE))
+        .expect("main.nr is one of the sources")
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use acvm::{
+    blackbox_solver::StubbedBlackBoxSolver,
+    pwg::{ACVMStatus, ACVM},
+    FieldElement,
+};
+use noirc_abi::{input_parser::InputValue, AbiType, InputMap};
+use noirc_driver::CompileOptions;
+
+use common::compile_main;
+
+#[test]
+fn comparison_bound_with_let_evaluates_to_boolean() {
+    let source = "
+fn main(a: u8, c: u8) -> pub bool {
+    let b = a < c;
+    b
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    let return_type = program.abi.return_type.as_ref().expect("main should return a value");
+    assert_eq!(return_type.abi_type, AbiType::Boolean);
+
+    let circuit = &program.program.functions[0];
+    let return_witness = *circuit.return_values.0.iter().next().expect("expected a return value");
+
+    for (a, c, expected) in [(3_u128, 5_u128, true), (5, 3, false), (4, 4, false)] {
+        let inputs: InputMap = BTreeMap::from([
+            ("a".to_string(), InputValue::Field(FieldElement::from(a))),
+            ("c".to_string(), InputValue::Field(FieldElement::from(c))),
+        ]);
+        let initial_witness = program.abi.encode(&inputs, None).expect("inputs should encode");
+
+        let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &circuit.opcodes, initial_witness);
+        assert_eq!(acvm.solve(), ACVMStatus::Solved);
+        let witness_map = acvm.finalize();
+
+        assert_eq!(witness_map[&return_witness], FieldElement::from(expected));
+    }
+}
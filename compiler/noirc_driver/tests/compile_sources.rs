@@ -0,0 +1,76 @@
+mod common;
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use noirc_driver::{compile_binaries, compile_sources, CompileOptions};
+
+use common::compile_main;
+
+#[test]
+fn compiles_program_from_in_memory_sources() {
+    let source = "
+fn main(x: Field, y: pub Field) {
+    assert(x != y);
+}";
+    let (program, warnings) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    assert!(warnings.is_empty());
+    assert_eq!(program.abi.parameters.len(), 2);
+}
+
+#[test]
+fn errors_on_missing_root_file() {
+    let sources = BTreeMap::from([(PathBuf::from("lib.nr"), "fn main() {}".to_string())]);
+
+    let errors = compile_sources(sources, Path::new("main.nr"), &CompileOptions::default())
+        .expect_err("root file is not in the sources");
+    assert_eq!(
+        errors[0].diagnostic.message,
+        "root file main.nr is not one of the provided sources"
+    );
+}
+
+#[test]
+fn resolves_modules_from_in_memory_sources() {
+    let main = "
+mod foo;
+
+fn main(x: Field) -> pub Field {
+    foo::double(x)
+}";
+    let foo = "
+pub fn double(x: Field) -> Field {
+    x * 2
+}";
+    let sources = BTreeMap::from([
+        (PathBuf::from("main.nr"), main.to_string()),
+        (PathBuf::from("foo.nr"), foo.to_string()),
+    ]);
+
+    let (program, _) = compile_sources(sources, Path::new("main.nr"), &CompileOptions::default())
+        .expect("module should be resolved without touching the filesystem");
+    assert!(program.abi.return_type.is_some());
+}
+
+#[test]
+fn compiles_each_binary_separately() {
+    let sources = BTreeMap::from([
+        (PathBuf::from("bin/add.nr"), "fn main(x: Field, y: pub Field) { assert(x + 1 == y); }"),
+        (PathBuf::from("bin/square.nr"), "mod helpers;\nfn main(x: Field) { helpers::check(x); }"),
+        (PathBuf::from("bin/square/helpers.nr"), "pub fn check(x: Field) { assert(x * x != 2); }"),
+    ])
+    .into_iter()
+    .map(|(path, source)| (path, source.to_string()))
+    .collect();
+
+    let (programs, _) = compile_binaries(sources, Path::new("bin"), &CompileOptions::default())
+        .expect("binaries should compile");
+
+    assert_eq!(programs.keys().collect::<Vec<_>>(), vec!["add", "square"]);
+    assert_eq!(programs["add"].abi.parameters.len(), 2);
+    assert_eq!(programs["square"].abi.parameters.len(), 1);
+    assert!(programs.values().all(|program| !program.program.functions[0].opcodes.is_empty()));
+}
@@ -1,9 +1,10 @@
-use std::path::Path;
+mod common;
 
 use fm::FileId;
-use noirc_driver::{file_manager_with_stdlib, prepare_crate, CompileOptions, ErrorsAndWarnings};
+use noirc_driver::{CompileOptions, ErrorsAndWarnings};
 use noirc_errors::CustomDiagnostic;
-use noirc_frontend::hir::{def_map::parse_file, Context};
+
+use common::prepare_main;
 
 #[test]
 fn reject_crates_containing_multiple_contracts() -> Result<(), ErrorsAndWarnings> {
@@ -12,20 +13,7 @@ contract Foo {}
 
 contract Bar {}";
 
-    let root = Path::new("");
-    let file_name = Path::new("main.nr");
-    let mut file_manager = file_manager_with_stdlib(root);
-    file_manager.add_file_with_source(file_name, source.to_owned()).expect(
-        "Adding source buffer to file manager should never fail when file manager is empty",
-    );
-    let parsed_files = file_manager
-        .as_file_map()
-        .all_file_ids()
-        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
-        .collect();
-
-    let mut context = Context::new(file_manager, parsed_files);
-    let root_crate_id = prepare_crate(&mut context, file_name);
+    let (mut context, root_crate_id) = prepare_main(source);
 
     let errors =
         noirc_driver::compile_contract(&mut context, root_crate_id, &CompileOptions::default())
@@ -0,0 +1,54 @@
+mod common;
+
+use acvm::{acir::circuit::Opcode, FieldElement};
+use noirc_abi::AbiType;
+use noirc_driver::CompileOptions;
+
+use common::compile_main;
+
+#[test]
+fn indexing_global_array_with_constant_is_folded() {
+    let source = "
+global TABLE = [2, 4, 8];
+
+fn main(x: Field) {
+    assert(x == TABLE[2]);
+    assert(TABLE.len() == 3);
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+
+    // The lookup is folded at compile time so no memory is needed to hold the table.
+    let opcodes = &program.program.functions[0].opcodes;
+    assert_eq!(opcodes.len(), 1);
+    let Opcode::AssertZero(expression) = &opcodes[0] else {
+        panic!("Expected a single assertion, got {opcodes:?}");
+    };
+    assert!(expression.mul_terms.is_empty());
+    assert_eq!(expression.linear_combinations.len(), 1);
+    // The assertion is either `x - 8 == 0` or `8 - x == 0`.
+    let eight = FieldElement::from(8_u128);
+    assert!(expression.q_c == eight || expression.q_c == -eight);
+}
+
+#[test]
+fn length_of_global_array_can_be_used_as_array_length() {
+    let source = "
+global TABLE = [2, 4, 8];
+global LEN = TABLE.len();
+
+fn main(x: [Field; LEN]) {
+    for i in 0..LEN {
+        assert(x[i] == TABLE[i]);
+    }
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+
+    let AbiType::Array { length, .. } = &program.abi.parameters[0].typ else {
+        panic!("Expected an array parameter, got {:?}", program.abi.parameters[0].typ);
+    };
+    assert_eq!(*length, 3);
+}
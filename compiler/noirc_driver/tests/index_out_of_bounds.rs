@@ -0,0 +1,47 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+use noirc_errors::FileDiagnostic;
+
+use common::compile_main;
+
+fn compile_errors(source: &str) -> Vec<FileDiagnostic> {
+    compile_main(source, &CompileOptions::default()).expect_err("program should fail to compile")
+}
+
+#[test]
+fn constant_index_out_of_bounds_reports_index_and_length() {
+    let source = "
+fn main(arr: [Field; 3]) -> pub Field {
+    arr[10]
+}";
+
+    let errors = compile_errors(source);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].diagnostic.message,
+        "Index out of bounds, array has size 3, but index was 10"
+    );
+
+    let span = errors[0].diagnostic.secondaries[0].span;
+    let access_start = source.find("arr[10]").unwrap() as u32;
+    assert!(span.start() >= access_start && span.end() <= access_start + "arr[10]".len() as u32);
+}
+
+#[test]
+fn constant_sub_array_out_of_bounds_is_reported() {
+    let source = "
+fn main(arr: [Field; 3]) -> pub [Field; 2] {
+    arr[2..4]
+}";
+
+    let errors = compile_errors(source);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].diagnostic.message,
+        "Range 2..4 is out of bounds for an array of length 3"
+    );
+
+    let slice_start = source.find("arr[2..4]").unwrap() as u32;
+    assert_eq!(errors[0].diagnostic.secondaries[0].span.start(), slice_start);
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+use noirc_errors::FileDiagnostic;
+
+use common::compile_main;
+
+const SOURCE: &str = "
+fn main(x: Field) -> pub Field {
+    let mut sum = x;
+    for i in 0..100 {
+        sum += i;
+    }
+    sum
+}";
+
+fn compile_with_loop_limit(max_loop_iterations: Option<usize>) -> Result<(), Vec<FileDiagnostic>> {
+    let options = CompileOptions { max_loop_iterations, ..CompileOptions::default() };
+    compile_main(SOURCE, &options).map(|_| ())
+}
+
+#[test]
+fn default_loop_limit_allows_small_loops() {
+    assert!(compile_with_loop_limit(None).is_ok());
+    assert!(compile_with_loop_limit(Some(100)).is_ok());
+}
+
+#[test]
+fn reports_loops_exceeding_unroll_limit() {
+    let errors = compile_with_loop_limit(Some(10)).expect_err("loop should exceed the limit");
+    assert_eq!(errors.len(), 1);
+
+    let diagnostic = &errors[0].diagnostic;
+    assert_eq!(diagnostic.message, "Loop would be unrolled more than 10 times");
+
+    // The error points at the start of the loop's range
+    let range_start = SOURCE.find("0..100").unwrap() as u32;
+    assert_eq!(diagnostic.secondaries.len(), 1);
+    assert_eq!(diagnostic.secondaries[0].span.start(), range_start);
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use acvm::acir::circuit::ExpressionWidth;
+use noirc_driver::CompileOptions;
+
+use common::compile_main;
+
+fn compile_with_opcode_limit(
+    source: &str,
+    max_opcodes: usize,
+    expression_width: ExpressionWidth,
+) -> Result<usize, String> {
+    let options = CompileOptions {
+        max_opcodes: Some(max_opcodes),
+        expression_width: Some(expression_width),
+        ..CompileOptions::default()
+    };
+    match compile_main(source, &options) {
+        Ok((program, _)) => Ok(program.program.functions[0].opcodes.len()),
+        Err(errors) => Err(errors[0].diagnostic.message.clone()),
+    }
+}
+
+#[test]
+fn rejects_circuits_exceeding_opcode_limit() {
+    let source = "
+fn main(x: Field, y: pub Field) {
+    let mut acc = x;
+    for _ in 0..10 {
+        acc = acc * acc;
+    }
+    assert(acc != y);
+}";
+    let width = ExpressionWidth::Unbounded;
+
+    let num_opcodes = compile_with_opcode_limit(source, usize::MAX, width).unwrap();
+    assert!(num_opcodes > 1);
+
+    assert_eq!(compile_with_opcode_limit(source, num_opcodes, width), Ok(num_opcodes));
+    assert_eq!(
+        compile_with_opcode_limit(source, 1, width),
+        Err(format!("Circuit contains {num_opcodes} opcodes which exceeds the limit of 1"))
+    );
+}
+
+#[test]
+fn opcode_limit_applies_to_circuit_optimized_for_expression_width() {
+    // A single assertion over six witnesses which must be split to fit in width 3 gates.
+    let source = "
+fn main(a: Field, b: Field, c: Field, d: Field, e: Field, y: pub Field) {
+    assert(a + b + c + d + e == y);
+}";
+
+    // Before being optimized the circuit only holds the one assertion.
+    assert_eq!(compile_with_opcode_limit(source, 1, ExpressionWidth::Unbounded), Ok(1));
+
+    let error = compile_with_opcode_limit(source, 1, ExpressionWidth::Bounded { width: 3 })
+        .expect_err("optimized circuit should exceed the limit");
+    assert!(error.starts_with("Circuit contains "), "unexpected error: {error}");
+    assert!(error.ends_with(" opcodes which exceeds the limit of 1"), "unexpected error: {error}");
+}
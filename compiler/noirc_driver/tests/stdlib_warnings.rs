@@ -1,7 +1,8 @@
-use std::path::Path;
+mod common;
 
-use noirc_driver::{file_manager_with_stdlib, prepare_crate, ErrorsAndWarnings};
-use noirc_frontend::hir::{def_map::parse_file, Context};
+use noirc_driver::ErrorsAndWarnings;
+
+use common::prepare_main;
 
 #[test]
 fn stdlib_does_not_produce_constant_warnings() -> Result<(), ErrorsAndWarnings> {
@@ -9,20 +10,7 @@ fn stdlib_does_not_produce_constant_warnings() -> Result<(), ErrorsAndWarnings>
     // be emitted.
     let source = "fn main() {}";
 
-    let root = Path::new("");
-    let file_name = Path::new("main.nr");
-    let mut file_manager = file_manager_with_stdlib(root);
-    file_manager.add_file_with_source(file_name, source.to_owned()).expect(
-        "Adding source buffer to file manager should never fail when file manager is empty",
-    );
-    let parsed_files = file_manager
-        .as_file_map()
-        .all_file_ids()
-        .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
-        .collect();
-
-    let mut context = Context::new(file_manager, parsed_files);
-    let root_crate_id = prepare_crate(&mut context, file_name);
+    let (mut context, root_crate_id) = prepare_main(source);
 
     let ((), warnings) = noirc_driver::check_crate(&mut context, root_crate_id, false, false)?;
 
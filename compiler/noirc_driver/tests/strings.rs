@@ -0,0 +1,36 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use acvm::{
+    blackbox_solver::StubbedBlackBoxSolver,
+    pwg::{ACVMStatus, ACVM},
+};
+use noirc_abi::{input_parser::InputValue, AbiType, InputMap};
+use noirc_driver::CompileOptions;
+
+use common::compile_main;
+
+#[test]
+fn string_parameter_is_passed_as_fixed_length_bytes() {
+    let source = r#"
+fn main(message: str<8>) {
+    assert(message == "noirlang");
+}"#;
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    assert_eq!(program.abi.parameters[0].typ, AbiType::String { length: 8 });
+
+    let circuit = &program.program.functions[0];
+    // Each byte of the string is passed as its own witness.
+    assert_eq!(program.abi.field_count(), 8);
+    for (message, expected_solved) in [("noirlang", true), ("noirlan!", false)] {
+        let inputs: InputMap =
+            BTreeMap::from([("message".to_string(), InputValue::String(message.to_string()))]);
+        let initial_witness = program.abi.encode(&inputs, None).expect("inputs should encode");
+
+        let mut acvm = ACVM::new(&StubbedBlackBoxSolver, &circuit.opcodes, initial_witness);
+        assert_eq!(acvm.solve() == ACVMStatus::Solved, expected_solved);
+    }
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+use common::compile_main;
+
+const UNCONSTRAINED_WITNESS_WARNING: &str = "Witness is computed but never constrained";
+
+/// Returns the number of unconstrained witness warnings raised when compiling `source`.
+fn unconstrained_witness_warnings(source: &str, warn_unconstrained_witnesses: bool) -> usize {
+    let options = CompileOptions { warn_unconstrained_witnesses, ..CompileOptions::default() };
+    let (_, warnings) = compile_main(source, &options).expect("program should compile");
+    warnings
+        .iter()
+        .filter(|warning| warning.diagnostic.message == UNCONSTRAINED_WITNESS_WARNING)
+        .count()
+}
+
+#[test]
+fn unconstrained_witness_warnings_are_opt_in() {
+    // `y` is only consumed by another unconstrained call so it never appears in a constraint.
+    let source = "
+unconstrained fn double(x: Field) -> Field {
+    x * 2
+}
+
+fn main(x: Field) -> pub Field {
+    let y = double(x);
+    let z = double(y);
+    assert(z == x * 4);
+    z
+}";
+
+    assert_eq!(unconstrained_witness_warnings(source, false), 0);
+    assert_eq!(unconstrained_witness_warnings(source, true), 1);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+use common::compile_main;
+
+#[test]
+fn splits_input_and_intermediate_witnesses() {
+    let source = "
+fn main(x: Field, y: pub Field) {
+    let mut acc = x;
+    for _ in 0..3 {
+        acc = acc * acc;
+    }
+    assert(acc != y);
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    let num_witnesses = program.program.functions[0].num_vars() as usize;
+
+    assert_eq!(program.num_input_witnesses(), 2);
+    let num_intermediate_witnesses = program.num_intermediate_witnesses();
+    assert!(num_intermediate_witnesses > 0);
+    assert_eq!(program.num_input_witnesses() + num_intermediate_witnesses, num_witnesses);
+}
+
+#[test]
+fn compiled_circuit_has_no_unused_witnesses() {
+    let source = "
+fn main(x: Field, y: pub Field) -> pub Field {
+    let z = x * y;
+    assert(z != 0);
+    z + x
+}";
+
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    let circuit = &program.program.functions[0];
+    let (compacted, _) = circuit.compact_witnesses();
+    assert_eq!(compacted.num_vars(), circuit.num_vars());
+}
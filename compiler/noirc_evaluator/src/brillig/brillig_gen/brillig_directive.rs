@@ -98,3 +98,61 @@ pub(crate) fn directive_quotient(bit_size: u32) -> GeneratedBrillig {
         locations: Default::default(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::{acir::brillig::Value, FieldElement};
+
+    use crate::brillig::brillig_ir::tests::create_and_run_vm;
+
+    use super::{directive_invert, directive_quotient};
+
+    #[test]
+    fn invert_computes_field_inverse() {
+        let bytecode = directive_invert().byte_code;
+        let x = FieldElement::from(7_u128);
+
+        let (vm, return_data_offset, return_data_size) =
+            create_and_run_vm(vec![Value::from(x)], &bytecode);
+        assert_eq!(return_data_size, 1);
+
+        let inverse = vm.get_memory()[return_data_offset].to_field();
+        assert_eq!(inverse * x, FieldElement::one());
+    }
+
+    #[test]
+    fn invert_returns_zero_for_zero() {
+        let bytecode = directive_invert().byte_code;
+
+        let (vm, return_data_offset, _) =
+            create_and_run_vm(vec![Value::from(FieldElement::zero())], &bytecode);
+        assert_eq!(vm.get_memory()[return_data_offset].to_field(), FieldElement::zero());
+    }
+
+    #[test]
+    fn quotient_computes_quotient_and_remainder() {
+        let bytecode = directive_quotient(32).byte_code;
+        let inputs = vec![Value::from(17_usize), Value::from(5_usize)];
+
+        let (vm, return_data_offset, return_data_size) = create_and_run_vm(inputs, &bytecode);
+        assert_eq!(return_data_size, 2);
+
+        let memory = vm.get_memory();
+        assert_eq!(memory[return_data_offset].to_field(), FieldElement::from(3_u128));
+        assert_eq!(memory[return_data_offset + 1].to_field(), FieldElement::from(2_u128));
+    }
+
+    #[test]
+    fn quotient_by_power_of_two_truncates() {
+        // Truncating `a` to 8 bits divides it by 2^8: the remainder holds the low bits and
+        // the quotient holds the bits which were shifted out.
+        let bytecode = directive_quotient(32).byte_code;
+        let inputs = vec![Value::from(0x1234_usize), Value::from(1_usize << 8)];
+
+        let (vm, return_data_offset, _) = create_and_run_vm(inputs, &bytecode);
+
+        let memory = vm.get_memory();
+        assert_eq!(memory[return_data_offset].to_field(), FieldElement::from(0x12_u128));
+        assert_eq!(memory[return_data_offset + 1].to_field(), FieldElement::from(0x34_u128));
+    }
+}
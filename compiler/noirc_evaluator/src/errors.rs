@@ -9,7 +9,7 @@
 //! An Error of the latter is an error in the implementation of the compiler
 use acvm::{acir::native_types::Expression, FieldElement};
 use iter_extended::vecmap;
-use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic};
+use noirc_errors::{CustomDiagnostic as Diagnostic, DiagnosticKind, FileDiagnostic};
 use thiserror::Error;
 
 use crate::ssa::ir::{dfg::CallStack, types::NumericType};
@@ -50,6 +50,10 @@ pub enum RuntimeError {
     UnconstrainedSliceReturnToConstrained { call_stack: CallStack },
     #[error("All `oracle` methods should be wrapped in an unconstrained fn")]
     UnconstrainedOracleReturnToConstrained { call_stack: CallStack },
+    #[error("Circuit contains {num_opcodes} opcodes which exceeds the limit of {limit}")]
+    OpcodeLimitExceeded { num_opcodes: usize, limit: usize, call_stack: CallStack },
+    #[error("Loop would be unrolled more than {limit} times")]
+    LoopUnrollLimitExceeded { limit: usize, call_stack: CallStack },
 }
 
 // We avoid showing the actual lhs and rhs since most of the time they are just 0
@@ -79,12 +83,27 @@ impl From<SsaReport> for FileDiagnostic {
                     InternalWarning::VerifyProof { call_stack } => {
                         ("verify_proof(...) aggregates data for the verifier, the actual verification will be done when the full proof is verified using nargo verify. nargo prove may generate an invalid proof if bad data is used as input to verify_proof".to_string(), call_stack)
                     },
+                    InternalWarning::UnconstrainedWitness { call_stack } => {
+                        ("This value does not appear in any constraint so a malicious prover may set it to any value. Consider constraining it against a value computed in a constrained context".to_string(), call_stack)
+                    },
+                    InternalWarning::ConstantOverflow { call_stack, .. } => {
+                        ("This expression is evaluated to a constant which does not fit in its type so the program will always fail".to_string(), call_stack)
+                    },
                 };
                 let call_stack = vecmap(call_stack, |location| location);
                 let file_id = call_stack.last().map(|location| location.file).unwrap_or_default();
-                let location = call_stack.last().expect("Expected RuntimeError to have a location");
-                let diagnostic =
-                    Diagnostic::simple_warning(message, secondary_message, location.span);
+                let diagnostic = match call_stack.last() {
+                    Some(location) => {
+                        Diagnostic::simple_warning(message, secondary_message, location.span)
+                    }
+                    // Instructions inserted by the compiler may not carry a location.
+                    None => {
+                        let mut diagnostic = Diagnostic::from_message(&message);
+                        diagnostic.kind = DiagnosticKind::Warning;
+                        diagnostic.add_note(secondary_message);
+                        diagnostic
+                    }
+                };
                 diagnostic.in_file(file_id).with_call_stack(call_stack)
             }
         }
@@ -97,6 +116,10 @@ pub enum InternalWarning {
     ReturnConstant { call_stack: CallStack },
     #[error("Calling std::verify_proof(...) does not verify a proof")]
     VerifyProof { call_stack: CallStack },
+    #[error("Witness is computed but never constrained")]
+    UnconstrainedWitness { call_stack: CallStack },
+    #[error("Constant value overflows {max_bit_size} bits")]
+    ConstantOverflow { max_bit_size: u32, call_stack: CallStack },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Error)]
@@ -141,7 +164,9 @@ impl RuntimeError {
             | RuntimeError::NestedSlice { call_stack, .. }
             | RuntimeError::BigIntModulus { call_stack, .. }
             | RuntimeError::UnconstrainedSliceReturnToConstrained { call_stack }
-            | RuntimeError::UnconstrainedOracleReturnToConstrained { call_stack } => call_stack,
+            | RuntimeError::UnconstrainedOracleReturnToConstrained { call_stack }
+            | RuntimeError::OpcodeLimitExceeded { call_stack, .. }
+            | RuntimeError::LoopUnrollLimitExceeded { call_stack, .. } => call_stack,
         }
     }
 }
@@ -177,6 +202,22 @@ impl RuntimeError {
                     location.span,
                 )
             }
+            RuntimeError::LoopUnrollLimitExceeded { .. } => {
+                let primary_message = self.to_string();
+                let secondary_message = "Consider reducing the loop's bound or raising the limit with --max-loop-iterations".to_string();
+
+                // The loop has no location if it wasn't entered through a `jmp` carrying one.
+                match self.call_stack().back() {
+                    Some(location) => {
+                        Diagnostic::simple_error(primary_message, secondary_message, location.span)
+                    }
+                    None => {
+                        let mut diagnostic = Diagnostic::from_message(&primary_message);
+                        diagnostic.add_note(secondary_message);
+                        diagnostic
+                    }
+                }
+            }
             _ => {
                 let message = self.to_string();
                 let location =
@@ -187,3 +228,52 @@ impl RuntimeError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noirc_errors::{FileDiagnostic, Location, Span};
+
+    use super::{InternalWarning, RuntimeError, SsaReport};
+    use crate::ssa::ir::dfg::CallStack;
+
+    #[test]
+    fn runtime_error_points_at_innermost_location() {
+        let file = Location::dummy().file;
+        let caller = Location::new(Span::from(0..4), file);
+        let array_access = Location::new(Span::from(10..20), file);
+        let error = RuntimeError::IndexOutOfBounds {
+            index: 5,
+            array_size: 3,
+            call_stack: [caller, array_access].into_iter().collect(),
+        };
+
+        let FileDiagnostic { file_id, diagnostic, call_stack } = error.into();
+        assert_eq!(file_id, file);
+        assert_eq!(diagnostic.message, "Index out of bounds, array has size 3, but index was 5");
+        assert_eq!(diagnostic.secondaries.len(), 1);
+        assert_eq!(diagnostic.secondaries[0].span, array_access.span);
+        assert_eq!(call_stack, vec![caller, array_access]);
+    }
+
+    #[test]
+    fn loop_unroll_limit_without_location() {
+        let error =
+            RuntimeError::LoopUnrollLimitExceeded { limit: 10, call_stack: CallStack::new() };
+
+        let FileDiagnostic { diagnostic, call_stack, .. } = error.into();
+        assert_eq!(diagnostic.message, "Loop would be unrolled more than 10 times");
+        assert!(diagnostic.secondaries.is_empty());
+        assert!(call_stack.is_empty());
+    }
+
+    #[test]
+    fn constant_overflow_without_location() {
+        let warning =
+            InternalWarning::ConstantOverflow { max_bit_size: 8, call_stack: CallStack::new() };
+
+        let FileDiagnostic { diagnostic, call_stack, .. } = SsaReport::Warning(warning).into();
+        assert_eq!(diagnostic.message, "Constant value overflows 8 bits");
+        assert!(diagnostic.is_warning());
+        assert!(call_stack.is_empty());
+    }
+}
@@ -18,6 +18,7 @@ use acvm::acir::{
     native_types::Witness,
 };
 
+use iter_extended::vecmap;
 use noirc_errors::debug_info::DebugInfo;
 
 use noirc_frontend::{
@@ -32,6 +33,7 @@ pub(super) mod function_builder;
 pub mod ir;
 mod opt;
 pub mod ssa_gen;
+mod unconstrained_witnesses;
 
 /// Optimize the given program by converting it into SSA
 /// form and performing optimizations there. When finished,
@@ -41,6 +43,7 @@ pub(crate) fn optimize_into_acir(
     print_ssa_passes: bool,
     print_brillig_trace: bool,
     force_brillig_output: bool,
+    max_loop_iterations: usize,
 ) -> Result<GeneratedAcir, RuntimeError> {
     let abi_distinctness = program.return_distinctness;
 
@@ -53,7 +56,7 @@ pub(crate) fn optimize_into_acir(
         // Run mem2reg with the CFG separated into blocks
         .run_pass(Ssa::mem2reg, "After Mem2Reg:")
         .try_run_pass(Ssa::evaluate_assert_constant, "After Assert Constant:")?
-        .try_run_pass(Ssa::unroll_loops, "After Unrolling:")?
+        .try_run_pass(|ssa| ssa.unroll_loops(max_loop_iterations), "After Unrolling:")?
         .run_pass(Ssa::simplify_cfg, "After Simplifying:")
         .run_pass(Ssa::flatten_cfg, "After Flattening:")
         .run_pass(Ssa::remove_bit_shifts, "After Removing Bit Shifts:")
@@ -83,6 +86,8 @@ pub fn create_circuit(
     enable_ssa_logging: bool,
     enable_brillig_logging: bool,
     force_brillig_output: bool,
+    max_loop_iterations: usize,
+    check_unconstrained_witnesses: bool,
 ) -> Result<(Circuit, DebugInfo, Vec<Witness>, Vec<Witness>, Vec<SsaReport>), RuntimeError> {
     let debug_variables = program.debug_variables.clone();
     let debug_types = program.debug_types.clone();
@@ -94,6 +99,7 @@ pub fn create_circuit(
         enable_ssa_logging,
         enable_brillig_logging,
         force_brillig_output,
+        max_loop_iterations,
     )?;
     let opcodes = generated_acir.take_opcodes();
     let current_witness_index = generated_acir.current_witness_index().0;
@@ -102,7 +108,7 @@ pub fn create_circuit(
         locations,
         input_witnesses,
         assert_messages,
-        warnings,
+        mut warnings,
         ..
     } = generated_acir;
 
@@ -123,6 +129,14 @@ pub fn create_circuit(
         recursive,
     };
 
+    // This analysis is opt-in as values which only feed into further unconstrained code are
+    // reported too, even when that code's results end up being constrained.
+    if check_unconstrained_witnesses {
+        warnings.extend(unconstrained_witnesses::check_for_unconstrained_witnesses(
+            &circuit, &locations,
+        ));
+    }
+
     // This converts each im::Vector in the BTreeMap to a Vec
     let locations = locations
         .into_iter()
@@ -135,7 +149,13 @@ pub fn create_circuit(
     let (optimized_circuit, transformation_map) = acvm::compiler::optimize(circuit);
     debug_info.update_acir(transformation_map);
 
-    Ok((optimized_circuit, debug_info, input_witnesses, return_witnesses, warnings))
+    // Witnesses which are no longer referenced still cost the backend a variable each,
+    // so the remaining ones are renumbered to remove any gaps.
+    let (compacted_circuit, witness_map) = optimized_circuit.compact_witnesses();
+    let input_witnesses = vecmap(&input_witnesses, |witness| witness_map[witness]);
+    let return_witnesses = vecmap(&return_witnesses, |witness| witness_map[witness]);
+
+    Ok((compacted_circuit, debug_info, input_witnesses, return_witnesses, warnings))
 }
 
 // Takes each function argument and partitions the circuit's inputs witnesses according to its visibility.
@@ -201,7 +221,7 @@ impl SsaBuilder {
     /// The same as `run_pass` but for passes that may fail
     fn try_run_pass(
         mut self,
-        pass: fn(Ssa) -> Result<Ssa, RuntimeError>,
+        pass: impl FnOnce(Ssa) -> Result<Ssa, RuntimeError>,
         msg: &str,
     ) -> Result<Self, RuntimeError> {
         self.ssa = pass(self.ssa)?;
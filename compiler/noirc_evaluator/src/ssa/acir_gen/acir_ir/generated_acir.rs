@@ -732,3 +732,25 @@ fn intrinsics_check_outputs(name: BlackBoxFunc, output_count: usize) {
 
     assert_eq!(expected_num_outputs,output_count,"Tried to call black box function {name} with {output_count} outputs, but this function's definition requires {expected_num_outputs} outputs");
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::FieldElement;
+
+    use super::GeneratedAcir;
+
+    #[test]
+    fn range_constraint_limit_follows_field_size() {
+        let mut acir = GeneratedAcir::default();
+        let input = acir.next_witness_index();
+
+        let max_num_bits = FieldElement::max_num_bits();
+        assert!(acir.range_constraint(input, max_num_bits - 1).is_ok());
+
+        let error = acir.range_constraint(input, max_num_bits).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("Range constraint of {max_num_bits} bits is too large for the Field size")
+        );
+    }
+}
@@ -508,6 +508,16 @@ impl Context {
             }
             Instruction::RangeCheck { value, max_bit_size, assert_message } => {
                 let acir_var = self.convert_numeric_value(*value, dfg)?;
+                if let Some(constant) = self.acir_context.var_to_expression(acir_var)?.to_const() {
+                    // A constant which doesn't fit in the range will always fail at execution time.
+                    if constant.num_bits() > *max_bit_size {
+                        let call_stack = self.acir_context.get_call_stack();
+                        warnings.push(SsaReport::Warning(InternalWarning::ConstantOverflow {
+                            max_bit_size: *max_bit_size,
+                            call_stack,
+                        }));
+                    }
+                }
                 self.acir_context.range_constrain_var(
                     acir_var,
                     &NumericType::Unsigned { bit_size: *max_bit_size },
@@ -2327,3 +2337,46 @@ fn can_omit_element_sizes_array(array_typ: &Type) -> bool {
 
     !types.iter().any(|typ| typ.contains_an_array())
 }
+
+#[cfg(test)]
+mod test {
+    use acvm::FieldElement;
+    use fxhash::FxHashMap as HashMap;
+    use noirc_errors::Location;
+    use noirc_frontend::Distinctness;
+
+    use crate::{
+        brillig::Brillig,
+        errors::{InternalWarning, SsaReport},
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{function::RuntimeType, instruction::BinaryOp, map::Id, types::Type},
+        },
+    };
+
+    #[test]
+    fn warns_on_constant_overflow() {
+        // fn main f0 {
+        //   b0():
+        //     v2 = add u8 200, u8 100
+        //     range_check v2 to 8 bits
+        //     return
+        // }
+        let mut builder = FunctionBuilder::new("main".into(), Id::test_new(0), RuntimeType::Acir);
+        builder.set_location(Location::dummy());
+        let lhs = builder.numeric_constant(FieldElement::from(200_u128), Type::unsigned(8));
+        let rhs = builder.numeric_constant(FieldElement::from(100_u128), Type::unsigned(8));
+        let sum = builder.insert_binary(lhs, BinaryOp::Add, rhs);
+        builder.insert_range_check(sum, 8, Some("attempt to add with overflow".to_string()));
+        builder.terminate_with_return(vec![]);
+
+        let ssa = builder.finish();
+        let acir = ssa
+            .into_acir(Brillig::default(), Distinctness::Distinct, &HashMap::default())
+            .expect("Should compile manually written SSA into ACIR");
+
+        assert_eq!(acir.warnings.len(), 1);
+        let SsaReport::Warning(warning) = &acir.warnings[0];
+        assert!(matches!(warning, InternalWarning::ConstantOverflow { max_bit_size: 8, .. }));
+    }
+}
@@ -56,3 +56,35 @@ pub(super) fn simplify_cast(
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::FieldElement;
+
+    use super::{simplify_cast, DataFlowGraph, SimplifyResult, Type};
+
+    fn fold_constant_cast(constant: u128, dst_typ: Type) -> FieldElement {
+        let mut dfg = DataFlowGraph::default();
+        let value = dfg.make_constant(constant.into(), Type::field());
+
+        match simplify_cast(value, &dst_typ, &mut dfg) {
+            SimplifyResult::SimplifiedTo(result) => {
+                assert_eq!(dfg.type_of_value(result), dst_typ);
+                dfg.get_numeric_constant(result).expect("cast should fold to a constant")
+            }
+            _ => panic!("cast of a constant should be simplified"),
+        }
+    }
+
+    #[test]
+    fn folds_constant_cast_to_unsigned() {
+        assert_eq!(fold_constant_cast(200, Type::unsigned(8)), FieldElement::from(200_u128));
+        // Values which do not fit in the target type are truncated
+        assert_eq!(fold_constant_cast(300, Type::unsigned(8)), FieldElement::from(44_u128));
+    }
+
+    #[test]
+    fn folds_constant_cast_to_field() {
+        assert_eq!(fold_constant_cast(200, Type::field()), FieldElement::from(200_u128));
+    }
+}
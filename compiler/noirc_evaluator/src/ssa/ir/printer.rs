@@ -211,3 +211,30 @@ fn display_constrain_error(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{function::RuntimeType, instruction::BinaryOp, map::Id, types::Type},
+    };
+
+    #[test]
+    fn display_binary_instruction() {
+        // fn main f0 {
+        //   b0(v0: Field, v1: Field):
+        //     v2 = add v0, v1
+        //     return v2
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+        let v2 = builder.insert_binary(v0, BinaryOp::Add, v1);
+        builder.terminate_with_return(vec![v2]);
+
+        let printed = builder.finish().to_string();
+        assert!(printed.contains("v2 = add v0, v1"), "unexpected SSA:\n{printed}");
+        assert!(printed.contains("return v2"), "unexpected SSA:\n{printed}");
+    }
+}
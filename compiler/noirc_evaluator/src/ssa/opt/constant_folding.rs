@@ -347,6 +347,61 @@ mod test {
         }
     }
 
+    #[test]
+    fn constrained_value_is_substituted() {
+        // fn main f0 {
+        //   b0(v0: Field):
+        //     constrain v0 == Field 5
+        //     v1 = mul v0, Field 3
+        //     return v1
+        // }
+        //
+        // Once `v0` is constrained to equal a constant, that constant should be propagated into
+        // later instructions so that `v1` folds to a constant.
+        let main_id = Id::test_new(0);
+
+        // Compiling main
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::field());
+
+        let five = builder.field_constant(5u128);
+        let three = builder.field_constant(3u128);
+
+        builder.insert_constrain(v0, five, None);
+        let v1 = builder.insert_binary(v0, BinaryOp::Mul, three);
+        builder.terminate_with_return(vec![v1]);
+
+        let ssa = builder.finish();
+        let main = ssa.main();
+        let instructions = main.dfg[main.entry_block()].instructions();
+        assert_eq!(instructions.len(), 2);
+
+        // Expected output:
+        //
+        // fn main f0 {
+        //   b0(v0: Field):
+        //     constrain v0 == Field 5
+        //     return Field 15
+        // }
+        let ssa = ssa.fold_constants_using_constraints();
+        let main = ssa.main();
+        let block = &main.dfg[main.entry_block()];
+        assert_eq!(block.instructions().len(), 1);
+        assert_eq!(main.dfg[block.instructions()[0]], Instruction::Constrain(v0, five, None));
+
+        match block.terminator() {
+            Some(TerminatorInstruction::Return { return_values, .. }) => {
+                let value = main
+                    .dfg
+                    .get_numeric_constant(return_values[0])
+                    .expect("Expected constant 15")
+                    .to_u128();
+                assert_eq!(value, 15);
+            }
+            _ => unreachable!("b0 should have a return terminator"),
+        }
+    }
+
     #[test]
     fn redundant_truncation() {
         // fn main f0 {
@@ -501,6 +556,61 @@ mod test {
         assert_eq!(main.dfg.resolve(new_add_instr_result), main.dfg.resolve(return_element));
     }
 
+    #[test]
+    fn constant_array_get_is_folded() {
+        // fn main f0 {
+        //   b0(v0: u32):
+        //     v1 = array_get [Field 10, Field 20, Field 30], index v0
+        //     return v1
+        // }
+        //
+        // After constructing this IR, we set the value of v0 to 1.
+        // The array_get should then be removed and the element returned directly.
+        let main_id = Id::test_new(0);
+
+        // Compiling main
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::unsigned(32));
+
+        let elements = [10u128, 20, 30].map(|value| builder.field_constant(value));
+        let array_type = Type::Array(Rc::new(vec![Type::field()]), 3);
+        let array = builder.array_constant(elements.to_vec().into(), array_type);
+
+        let v1 = builder.insert_array_get(array, v0, Type::field());
+        builder.terminate_with_return(vec![v1]);
+
+        let mut ssa = builder.finish();
+        let main = ssa.main_mut();
+        let instructions = main.dfg[main.entry_block()].instructions();
+        assert_eq!(instructions.len(), 1);
+
+        // Expected output:
+        //
+        // fn main f0 {
+        //   b0(u32 1: u32):
+        //     return Field 20
+        // }
+        let one = main.dfg.make_constant(1u128.into(), Type::unsigned(32));
+        main.dfg.set_value_from_id(v0, one);
+
+        let ssa = ssa.fold_constants();
+        let main = ssa.main();
+        let block = &main.dfg[main.entry_block()];
+        assert_eq!(block.instructions().len(), 0);
+
+        match block.terminator() {
+            Some(TerminatorInstruction::Return { return_values, .. }) => {
+                let value = main
+                    .dfg
+                    .get_numeric_constant(return_values[0])
+                    .expect("Expected constant 20")
+                    .to_u128();
+                assert_eq!(value, 20);
+            }
+            _ => unreachable!("b0 should have a return terminator"),
+        }
+    }
+
     #[test]
     fn instruction_deduplication() {
         // fn main f0 {
@@ -36,8 +36,12 @@ use fxhash::FxHashMap as HashMap;
 impl Ssa {
     /// Unroll all loops in each SSA function.
     /// If any loop cannot be unrolled, it is left as-is or in a partially unrolled state.
+    ///
+    /// Unrolling fails once any single loop has been unrolled more than `max_iterations` times
+    /// rather than attempting to build an arbitrarily large program. The limit applies to each
+    /// loop separately, including each copy of a loop nested within an unrolled loop.
     #[tracing::instrument(level = "trace", skip(self))]
-    pub(crate) fn unroll_loops(mut self) -> Result<Ssa, RuntimeError> {
+    pub(crate) fn unroll_loops(mut self, max_iterations: usize) -> Result<Ssa, RuntimeError> {
         for function in self.functions.values_mut() {
             // Loop unrolling in brillig can lead to a code explosion currently. This can
             // also be true for ACIR, but we have no alternative to unrolling in ACIR.
@@ -49,7 +53,7 @@ impl Ssa {
             // This check is always true with the addition of the above guard, but I'm
             // keeping it in case the guard on brillig functions is ever removed.
             let abort_on_error = function.runtime() == RuntimeType::Acir;
-            find_all_loops(function).unroll_each_loop(function, abort_on_error)?;
+            find_all_loops(function).unroll_each_loop(function, abort_on_error, max_iterations)?;
         }
         Ok(self)
     }
@@ -119,6 +123,7 @@ impl Loops {
         mut self,
         function: &mut Function,
         abort_on_error: bool,
+        max_iterations: usize,
     ) -> Result<(), RuntimeError> {
         while let Some(next_loop) = self.yet_to_unroll.pop() {
             // If we've previously modified a block in this loop we need to refresh the context.
@@ -126,16 +131,14 @@ impl Loops {
             if next_loop.blocks.iter().any(|block| self.modified_blocks.contains(block)) {
                 let mut new_context = find_all_loops(function);
                 new_context.failed_to_unroll = self.failed_to_unroll;
-                return new_context.unroll_each_loop(function, abort_on_error);
+                return new_context.unroll_each_loop(function, abort_on_error, max_iterations);
             }
 
             // Don't try to unroll the loop again if it is known to fail
             if !self.failed_to_unroll.contains(&next_loop.header) {
-                match unroll_loop(function, &self.cfg, &next_loop) {
+                match unroll_loop(function, &self.cfg, &next_loop, max_iterations) {
                     Ok(_) => self.modified_blocks.extend(next_loop.blocks),
-                    Err(call_stack) if abort_on_error => {
-                        return Err(RuntimeError::UnknownLoopBound { call_stack });
-                    }
+                    Err(error) if abort_on_error => return Err(error),
                     Err(_) => {
                         self.failed_to_unroll.insert(next_loop.header);
                     }
@@ -178,16 +181,36 @@ fn find_blocks_in_loop(
 }
 
 /// Unroll a single loop in the function.
-/// Returns Err(()) if it failed to unroll and Ok(()) otherwise.
+/// Returns Err if it failed to unroll and Ok(()) otherwise.
 fn unroll_loop(
     function: &mut Function,
     cfg: &ControlFlowGraph,
     loop_: &Loop,
-) -> Result<(), CallStack> {
+    max_iterations: usize,
+) -> Result<(), RuntimeError> {
     let mut unroll_into = get_pre_header(cfg, loop_);
-    let mut jump_value = get_induction_variable(function, unroll_into)?;
+    let mut jump_value = get_induction_variable(function, unroll_into)
+        .map_err(|call_stack| RuntimeError::UnknownLoopBound { call_stack })?;
+
+    // The jmp into the loop carries the location of the loop's range.
+    let loop_call_stack = match function.dfg[unroll_into].terminator() {
+        Some(TerminatorInstruction::Jmp { call_stack, .. }) => call_stack.clone(),
+        _ => CallStack::new(),
+    };
+
+    let mut iterations = 0;
+    while let Some(context) = unroll_loop_header(function, loop_, unroll_into, jump_value)
+        .map_err(|call_stack| RuntimeError::UnknownLoopBound { call_stack })?
+    {
+        iterations += 1;
+        if iterations > max_iterations {
+            let call_stack = loop_call_stack;
+            return Err(RuntimeError::LoopUnrollLimitExceeded {
+                limit: max_iterations,
+                call_stack,
+            });
+        }
 
-    while let Some(context) = unroll_loop_header(function, loop_, unroll_into, jump_value)? {
         let (last_block, last_value) = context.unroll_loop_iteration();
         unroll_into = last_block;
         jump_value = last_value;
@@ -462,9 +485,12 @@ impl<'f> LoopIteration<'f> {
 
 #[cfg(test)]
 mod tests {
-    use crate::ssa::{
-        function_builder::FunctionBuilder,
-        ir::{function::RuntimeType, instruction::BinaryOp, map::Id, types::Type},
+    use crate::{
+        errors::RuntimeError,
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{function::RuntimeType, instruction::BinaryOp, map::Id, types::Type},
+        },
     };
 
     #[test]
@@ -585,7 +611,7 @@ mod tests {
         // }
         // The final block count is not 1 because unrolling creates some unnecessary jmps.
         // If a simplify cfg pass is ran afterward, the expected block count will be 1.
-        let ssa = ssa.unroll_loops().expect("All loops should be unrolled");
+        let ssa = ssa.unroll_loops(usize::MAX).expect("All loops should be unrolled");
         assert_eq!(ssa.main().reachable_blocks().len(), 5);
     }
 
@@ -634,6 +660,52 @@ mod tests {
         assert_eq!(ssa.main().reachable_blocks().len(), 4);
 
         // Expected that we failed to unroll the loop
-        assert!(ssa.unroll_loops().is_err());
+        assert!(ssa.unroll_loops(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn loop_exceeding_unroll_limit() {
+        // fn main f0 {
+        //   b0():
+        //     jmp b1(Field 0)
+        //   b1(v0: Field):
+        //     v1 = lt v0, Field 1000000000
+        //     jmpif v1, then: b2, else: b3
+        //   b2():
+        //     v2 = add v0, Field 1
+        //     jmp b1(v2)
+        //   b3():
+        //     return
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+
+        let b1 = builder.insert_block();
+        let b2 = builder.insert_block();
+        let b3 = builder.insert_block();
+
+        let v0 = builder.add_block_parameter(b1, Type::field());
+
+        let zero = builder.field_constant(0u128);
+        builder.terminate_with_jmp(b1, vec![zero]);
+
+        builder.switch_to_block(b1);
+        let end = builder.field_constant(1_000_000_000u128);
+        let v1 = builder.insert_binary(v0, BinaryOp::Lt, end);
+        builder.terminate_with_jmpif(v1, b2, b3);
+
+        builder.switch_to_block(b2);
+        let one = builder.field_constant(1u128);
+        let v2 = builder.insert_binary(v0, BinaryOp::Add, one);
+        builder.terminate_with_jmp(b1, vec![v2]);
+
+        builder.switch_to_block(b3);
+        builder.terminate_with_return(vec![]);
+
+        let ssa = builder.finish();
+        match ssa.unroll_loops(100) {
+            Err(RuntimeError::LoopUnrollLimitExceeded { limit, .. }) => assert_eq!(limit, 100),
+            _ => panic!("Expected the loop to exceed the unroll limit"),
+        }
     }
 }
@@ -1186,6 +1186,9 @@ fn convert_operator(op: noirc_frontend::BinaryOpKind) -> BinaryOp {
         BinaryOpKind::GreaterEqual => BinaryOp::Lt, // Requires not
         BinaryOpKind::And => BinaryOp::And,
         BinaryOpKind::Or => BinaryOp::Or,
+        // Both operands are always evaluated, so on booleans these are the bitwise operators
+        BinaryOpKind::LogicalAnd => BinaryOp::And,
+        BinaryOpKind::LogicalOr => BinaryOp::Or,
         BinaryOpKind::Xor => BinaryOp::Xor,
         BinaryOpKind::ShiftLeft => BinaryOp::Shl,
         BinaryOpKind::ShiftRight => BinaryOp::Shr,
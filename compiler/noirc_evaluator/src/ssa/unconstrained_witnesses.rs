@@ -0,0 +1,160 @@
+//! This module defines an analysis over the final ACIR [`Circuit`] which detects witnesses
+//! whose values are computed (e.g. returned from a Brillig call) but which never appear in any constraint.
+//!
+//! The prover is free to assign any value to such a witness, which is a common source of soundness bugs.
+use std::collections::{BTreeMap, BTreeSet};
+
+use acvm::acir::{
+    circuit::{
+        brillig::BrilligOutputs, directives::Directive, opcodes::BlackBoxFuncCall, Circuit, Opcode,
+        OpcodeLocation,
+    },
+    native_types::{Expression, Witness},
+};
+
+use crate::errors::{InternalWarning, SsaReport};
+
+use super::ir::dfg::CallStack;
+
+/// Returns a warning for each opcode which writes to a witness which is not constrained by any other opcode.
+///
+/// Witnesses which are inputs to or return values of the circuit are never reported.
+pub(crate) fn check_for_unconstrained_witnesses(
+    circuit: &Circuit,
+    locations: &BTreeMap<OpcodeLocation, CallStack>,
+) -> Vec<SsaReport> {
+    let mut constrained_witnesses: BTreeSet<Witness> = circuit.circuit_arguments();
+    constrained_witnesses.extend(&circuit.return_values.0);
+    for opcode in &circuit.opcodes {
+        constrained_witnesses.extend(constrained_opcode_witnesses(opcode));
+    }
+
+    let mut warnings = Vec::new();
+    for (index, opcode) in circuit.opcodes.iter().enumerate() {
+        let has_unconstrained_output = unconstrained_opcode_outputs(opcode)
+            .iter()
+            .any(|witness| !constrained_witnesses.contains(witness));
+        if !has_unconstrained_output {
+            continue;
+        }
+
+        // We can only report a warning if we know where in the source code the opcode originated from.
+        if let Some(call_stack) = locations.get(&OpcodeLocation::Acir(index)) {
+            let call_stack = call_stack.clone();
+            warnings.push(SsaReport::Warning(InternalWarning::UnconstrainedWitness { call_stack }));
+        }
+    }
+    warnings
+}
+
+/// Returns the witnesses which are assigned by `opcode` without being constrained by it.
+fn unconstrained_opcode_outputs(opcode: &Opcode) -> Vec<Witness> {
+    match opcode {
+        Opcode::Brillig(brillig) => brillig
+            .outputs
+            .iter()
+            .flat_map(|output| match output {
+                BrilligOutputs::Simple(witness) => vec![*witness],
+                BrilligOutputs::Array(witnesses) => witnesses.clone(),
+            })
+            .collect(),
+        Opcode::Directive(Directive::ToLeRadix { b, .. }) => b.clone(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the witnesses whose values are constrained by `opcode`.
+fn constrained_opcode_witnesses(opcode: &Opcode) -> Vec<Witness> {
+    match opcode {
+        Opcode::AssertZero(expr) => expression_witnesses(expr),
+        Opcode::BlackBoxFuncCall(func) => black_box_witnesses(func),
+        Opcode::MemoryOp { op, predicate, .. } => [&op.operation, &op.index, &op.value]
+            .into_iter()
+            .chain(predicate)
+            .flat_map(expression_witnesses)
+            .collect(),
+        Opcode::MemoryInit { init, .. } => init.clone(),
+        Opcode::Call { inputs, outputs, .. } => inputs.iter().chain(outputs).copied().collect(),
+        Opcode::Brillig(_) | Opcode::Directive(_) => Vec::new(),
+    }
+}
+
+fn black_box_witnesses(func: &BlackBoxFuncCall) -> Vec<Witness> {
+    let inputs = func.get_inputs_vec().into_iter().map(|input| input.witness);
+    inputs.chain(func.get_outputs_vec()).collect()
+}
+
+fn expression_witnesses(expr: &Expression) -> Vec<Witness> {
+    let mul_witnesses = expr.mul_terms.iter().flat_map(|(_, lhs, rhs)| [*lhs, *rhs]);
+    mul_witnesses.chain(expr.linear_combinations.iter().map(|(_, witness)| *witness)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use acvm::{
+        acir::{
+            brillig::Opcode as BrilligOpcode,
+            circuit::{
+                brillig::{Brillig, BrilligInputs, BrilligOutputs},
+                Circuit, Opcode, OpcodeLocation, PublicInputs,
+            },
+            native_types::{Expression, Witness},
+        },
+        FieldElement,
+    };
+    use noirc_errors::Location;
+
+    use crate::errors::{InternalWarning, SsaReport};
+
+    use super::check_for_unconstrained_witnesses;
+
+    fn brillig_call(input: Witness, output: Witness) -> Opcode {
+        Opcode::Brillig(Brillig {
+            inputs: vec![BrilligInputs::Single(input.into())],
+            outputs: vec![BrilligOutputs::Simple(output)],
+            bytecode: vec![BrilligOpcode::Stop { return_data_offset: 0, return_data_size: 1 }],
+            predicate: None,
+        })
+    }
+
+    #[test]
+    fn warns_on_dangling_brillig_output() {
+        // w1 is the circuit's input, w2 is assigned by a Brillig call and never constrained,
+        // w3 is assigned by a Brillig call and then constrained to equal w1.
+        let constrained_output = Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: vec![
+                (FieldElement::one(), Witness(3)),
+                (-FieldElement::one(), Witness(1)),
+            ],
+            q_c: FieldElement::zero(),
+        };
+        let circuit = Circuit {
+            current_witness_index: 3,
+            opcodes: vec![
+                brillig_call(Witness(1), Witness(2)),
+                brillig_call(Witness(1), Witness(3)),
+                Opcode::AssertZero(constrained_output),
+            ],
+            private_parameters: [Witness(1)].into(),
+            public_parameters: PublicInputs::default(),
+            ..Circuit::default()
+        };
+
+        let call_stack: im::Vector<Location> = [Location::dummy()].into_iter().collect();
+        let locations: BTreeMap<_, _> = (0..circuit.opcodes.len())
+            .map(|index| (OpcodeLocation::Acir(index), call_stack.clone()))
+            .collect();
+
+        let warnings = check_for_unconstrained_witnesses(&circuit, &locations);
+        assert_eq!(warnings.len(), 1);
+        let SsaReport::Warning(warning) = &warnings[0];
+        assert_eq!(warning, &InternalWarning::UnconstrainedWitness { call_stack });
+
+        // Returning the dangling witness from the circuit removes the warning.
+        let circuit = Circuit { return_values: PublicInputs([Witness(2)].into()), ..circuit };
+        assert!(check_for_unconstrained_witnesses(&circuit, &locations).is_empty());
+    }
+}
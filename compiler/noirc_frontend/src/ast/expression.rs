@@ -16,6 +16,7 @@ pub enum ExpressionKind {
     Block(BlockExpression),
     Prefix(Box<PrefixExpression>),
     Index(Box<IndexExpression>),
+    SubArray(Box<SubArrayExpression>),
     Call(Box<CallExpression>),
     MethodCall(Box<MethodCallExpression>),
     Constructor(Box<ConstructorExpression>),
@@ -90,6 +91,10 @@ impl ExpressionKind {
         ExpressionKind::Literal(Literal::Bool(contents))
     }
 
+    pub fn character(contents: char) -> ExpressionKind {
+        ExpressionKind::Literal(Literal::Char(contents))
+    }
+
     pub fn string(contents: String) -> ExpressionKind {
         ExpressionKind::Literal(Literal::Str(contents))
     }
@@ -182,6 +187,12 @@ impl Expression {
         Expression::new(kind, span)
     }
 
+    pub fn sub_array(collection: Expression, start: u64, end: u64, span: Span) -> Expression {
+        let kind =
+            ExpressionKind::SubArray(Box::new(SubArrayExpression { collection, start, end }));
+        Expression::new(kind, span)
+    }
+
     pub fn cast(lhs: Expression, r#type: UnresolvedType, span: Span) -> Expression {
         let kind = ExpressionKind::Cast(Box::new(CastExpression { lhs, r#type }));
         Expression::new(kind, span)
@@ -225,6 +236,10 @@ pub enum BinaryOpKind {
     GreaterEqual,
     And,
     Or,
+    /// Boolean `&&`, evaluating both of its operands
+    LogicalAnd,
+    /// Boolean `||`, evaluating both of its operands
+    LogicalOr,
     Xor,
     ShiftRight,
     ShiftLeft,
@@ -247,6 +262,11 @@ impl BinaryOpKind {
         )
     }
 
+    /// Logical operators only accept and return booleans
+    pub fn is_logical(self) -> bool {
+        matches!(self, BinaryOpKind::LogicalAnd | BinaryOpKind::LogicalOr)
+    }
+
     pub fn is_valid_for_field_type(self) -> bool {
         matches!(
             self,
@@ -273,6 +293,8 @@ impl BinaryOpKind {
             BinaryOpKind::GreaterEqual => ">=",
             BinaryOpKind::And => "&",
             BinaryOpKind::Or => "|",
+            BinaryOpKind::LogicalAnd => "&&",
+            BinaryOpKind::LogicalOr => "||",
             BinaryOpKind::Xor => "^",
             BinaryOpKind::ShiftRight => ">>",
             BinaryOpKind::ShiftLeft => "<<",
@@ -294,6 +316,8 @@ impl BinaryOpKind {
             BinaryOpKind::GreaterEqual => Token::GreaterEqual,
             BinaryOpKind::And => Token::Ampersand,
             BinaryOpKind::Or => Token::Pipe,
+            BinaryOpKind::LogicalAnd => Token::LogicalAnd,
+            BinaryOpKind::LogicalOr => Token::LogicalOr,
             BinaryOpKind::Xor => Token::Caret,
             BinaryOpKind::ShiftLeft => Token::ShiftLeft,
             BinaryOpKind::ShiftRight => Token::ShiftRight,
@@ -306,6 +330,8 @@ impl BinaryOpKind {
 pub enum UnaryOp {
     Minus,
     Not,
+    /// Bitwise complement of an integer, `~x`
+    BitNot,
     MutableReference,
 
     /// If implicitly_added is true, this operation was implicitly added by the compiler for a
@@ -324,6 +350,7 @@ impl UnaryOp {
         match token {
             Token::Minus => Some(UnaryOp::Minus),
             Token::Bang => Some(UnaryOp::Not),
+            Token::Tilde => Some(UnaryOp::BitNot),
             _ => None,
         }
     }
@@ -334,6 +361,7 @@ pub enum Literal {
     Slice(ArrayLiteral),
     Bool(bool),
     Integer(FieldElement, /*sign*/ bool), // false for positive integer and true for negative
+    Char(char),
     Str(String),
     RawStr(String, u8),
     FmtStr(String),
@@ -396,6 +424,9 @@ pub struct FunctionDefinition {
     pub return_type: FunctionReturnType,
     pub return_visibility: Visibility,
     pub return_distinctness: Distinctness,
+
+    /// The outer doc comments (`///` or `/** */`) written directly above this function
+    pub doc_comments: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -451,6 +482,14 @@ pub struct IndexExpression {
     pub index: Expression, // XXX: We accept two types of indices, either a normal integer or a constant
 }
 
+/// `collection[start..end]`, a copy of the elements of an array within a constant range.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SubArrayExpression {
+    pub collection: Expression,
+    pub start: u64,
+    pub end: u64,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct BlockExpression(pub Vec<Statement>);
 
@@ -482,6 +521,7 @@ impl Display for ExpressionKind {
             Block(block) => block.fmt(f),
             Prefix(prefix) => prefix.fmt(f),
             Index(index) => index.fmt(f),
+            SubArray(sub_array) => sub_array.fmt(f),
             Call(call) => call.fmt(f),
             MethodCall(call) => call.fmt(f),
             Cast(cast) => cast.fmt(f),
@@ -527,6 +567,7 @@ impl Display for Literal {
                     write!(f, "{}", integer.to_u128())
                 }
             }
+            Literal::Char(c) => write!(f, "'{}'", c.escape_default()),
             Literal::Str(string) => write!(f, "\"{string}\""),
             Literal::RawStr(string, num_hashes) => {
                 let hashes: String =
@@ -563,6 +604,7 @@ impl Display for UnaryOp {
         match self {
             UnaryOp::Minus => write!(f, "-"),
             UnaryOp::Not => write!(f, "!"),
+            UnaryOp::BitNot => write!(f, "~"),
             UnaryOp::MutableReference => write!(f, "&mut"),
             UnaryOp::Dereference { .. } => write!(f, "*"),
         }
@@ -575,6 +617,12 @@ impl Display for IndexExpression {
     }
 }
 
+impl Display for SubArrayExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}..{}]", self.collection, self.start, self.end)
+    }
+}
+
 impl Display for CallExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let args = vecmap(&self.arguments, ToString::to_string);
@@ -631,6 +679,8 @@ impl Display for BinaryOpKind {
             BinaryOpKind::GreaterEqual => write!(f, ">="),
             BinaryOpKind::And => write!(f, "&"),
             BinaryOpKind::Or => write!(f, "|"),
+            BinaryOpKind::LogicalAnd => write!(f, "&&"),
+            BinaryOpKind::LogicalOr => write!(f, "||"),
             BinaryOpKind::Xor => write!(f, "^"),
             BinaryOpKind::ShiftLeft => write!(f, "<<"),
             BinaryOpKind::ShiftRight => write!(f, ">>"),
@@ -688,6 +738,7 @@ impl FunctionDefinition {
             return_type: return_type.clone(),
             return_visibility: Visibility::Private,
             return_distinctness: Distinctness::DuplicationAllowed,
+            doc_comments: Vec::new(),
         }
     }
 }
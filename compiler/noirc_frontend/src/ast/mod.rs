@@ -328,6 +328,8 @@ impl UnresolvedTypeExpression {
                     | BinaryOpKind::GreaterEqual
                     | BinaryOpKind::And
                     | BinaryOpKind::Or
+                    | BinaryOpKind::LogicalAnd
+                    | BinaryOpKind::LogicalOr
                     | BinaryOpKind::Xor
                     | BinaryOpKind::ShiftRight
                     | BinaryOpKind::ShiftLeft => {
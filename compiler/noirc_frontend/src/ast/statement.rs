@@ -5,8 +5,8 @@ use crate::lexer::token::SpannedToken;
 use crate::parser::{ParserError, ParserErrorReason};
 use crate::token::Token;
 use crate::{
-    BlockExpression, Expression, ExpressionKind, IndexExpression, MemberAccessExpression,
-    MethodCallExpression, UnresolvedType,
+    BlockExpression, Expression, ExpressionKind, FunctionReturnType, IndexExpression, Lambda,
+    MemberAccessExpression, MethodCallExpression, UnresolvedType,
 };
 use acvm::FieldElement;
 use iter_extended::vecmap;
@@ -35,6 +35,7 @@ pub enum StatementKind {
     Expression(Expression),
     Assign(AssignStatement),
     For(ForLoopStatement),
+    Function(LocalFunction),
     Break,
     Continue,
     // This is an expression with a trailing semi-colon
@@ -70,8 +71,8 @@ impl Statement {
                 }
                 self.kind
             }
-            // A semicolon on a for loop is optional and does nothing
-            StatementKind::For(_) => self.kind,
+            // A semicolon on a for loop or a local function is optional and does nothing
+            StatementKind::For(_) | StatementKind::Function(_) => self.kind,
 
             StatementKind::Expression(expr) => {
                 match (&expr.kind, semi, last_statement_in_block) {
@@ -633,6 +634,31 @@ pub struct ForLoopStatement {
     pub span: Span,
 }
 
+/// A function defined within a block, e.g. `fn double(x: Field) -> Field { x * 2 }`.
+/// It is only in scope for the statements following it in the enclosing block.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LocalFunction {
+    pub name: Ident,
+    pub parameters: Vec<(Pattern, UnresolvedType)>,
+    pub return_type: FunctionReturnType,
+    pub body: BlockExpression,
+    pub span: Span,
+}
+
+impl LocalFunction {
+    /// Desugars this function into a closure bound to its name:
+    /// `let name = |parameters| -> return_type { body };`
+    pub fn into_let(self) -> StatementKind {
+        let return_type = self.return_type.get_type().into_owned();
+        let body = Expression::new(ExpressionKind::Block(self.body), self.span);
+        let lambda = Lambda { parameters: self.parameters, return_type, body };
+        let lambda = Expression::new(ExpressionKind::Lambda(Box::new(lambda)), self.span);
+
+        let pattern = Pattern::Identifier(self.name);
+        StatementKind::new_let(((pattern, UnresolvedType::unspecified()), lambda))
+    }
+}
+
 impl Display for StatementKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -641,6 +667,7 @@ impl Display for StatementKind {
             StatementKind::Expression(expression) => expression.fmt(f),
             StatementKind::Assign(assign) => assign.fmt(f),
             StatementKind::For(for_loop) => for_loop.fmt(f),
+            StatementKind::Function(function) => function.fmt(f),
             StatementKind::Break => write!(f, "break"),
             StatementKind::Continue => write!(f, "continue"),
             StatementKind::Semi(semi) => write!(f, "{semi};"),
@@ -732,3 +759,18 @@ impl Display for ForLoopStatement {
         write!(f, "for {} in {range} {}", self.identifier, self.block)
     }
 }
+
+impl Display for LocalFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parameters = vecmap(&self.parameters, |(name, r#type)| format!("{name}: {type}"));
+
+        write!(
+            f,
+            "fn {}({}) -> {} {}",
+            self.name,
+            parameters.join(", "),
+            self.return_type,
+            self.body
+        )
+    }
+}
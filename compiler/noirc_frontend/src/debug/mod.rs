@@ -355,6 +355,9 @@ impl DebugInstrumenter {
                 self.walk_expr(&mut index_expr.collection);
                 self.walk_expr(&mut index_expr.index);
             }
+            ast::ExpressionKind::SubArray(sub_array) => {
+                self.walk_expr(&mut sub_array.collection);
+            }
             ast::ExpressionKind::Call(call_expr) => {
                 // TODO: push a stack frame or something here?
                 self.walk_expr(&mut call_expr.func);
@@ -444,6 +447,10 @@ impl DebugInstrumenter {
             ast::StatementKind::For(ref mut for_stmt) => {
                 self.walk_for(for_stmt);
             }
+            ast::StatementKind::Function(function) => {
+                stmt.kind = function.clone().into_let();
+                self.walk_statement(stmt);
+            }
             _ => {} // Constrain, Error
         }
     }
@@ -779,4 +779,21 @@ mod tests {
         // Now check for files in it's subdirectory
         find_module(&fm, sub_dir_file_id, "foo").unwrap();
     }
+
+    #[test]
+    fn path_resolve_in_memory_module() {
+        // None of these files exist on disk.
+        let mut fm = FileManager::new(Path::new("/in_memory"));
+
+        let main_id =
+            fm.add_file_with_source(Path::new("main.nr"), "mod foo;".to_string()).unwrap();
+        let foo_id = fm.add_file_with_source(Path::new("foo.nr"), "mod bar;".to_string()).unwrap();
+        let bar_id =
+            fm.add_file_with_source(Path::new("foo/bar.nr"), "fn bar() {}".to_string()).unwrap();
+
+        assert_eq!(find_module(&fm, main_id, "foo"), Ok(foo_id));
+        assert_eq!(find_module(&fm, foo_id, "bar"), Ok(bar_id));
+        assert_eq!(fm.fetch_file(bar_id), Some("fn bar() {}"));
+        find_module(&fm, main_id, "baz").unwrap_err();
+    }
 }
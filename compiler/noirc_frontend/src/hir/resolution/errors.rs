@@ -88,6 +88,8 @@ pub enum ResolverError {
     JumpInConstrainedFn { is_break: bool, span: Span },
     #[error("break/continue are only allowed within loops")]
     JumpOutsideLoop { is_break: bool, span: Span },
+    #[error("Range start {start} is greater than its end {end}")]
+    ReversedRange { start: u128, end: u128, span: Span },
 }
 
 impl ResolverError {
@@ -342,6 +344,11 @@ impl From<ResolverError> for Diagnostic {
                     span,
                 )
             },
+            ResolverError::ReversedRange { start, end, span } => Diagnostic::simple_error(
+                format!("Range start {start} is greater than its end {end}"),
+                "Ranges cannot iterate downwards so this loop would never run".into(),
+                span,
+            ),
         }
     }
 }
@@ -15,7 +15,7 @@ use crate::hir_def::expr::{
     HirArrayLiteral, HirBinaryOp, HirBlockExpression, HirCallExpression, HirCapturedVar,
     HirCastExpression, HirConstructorExpression, HirExpression, HirIdent, HirIfExpression,
     HirIndexExpression, HirInfixExpression, HirLambda, HirLiteral, HirMemberAccess,
-    HirMethodCallExpression, HirPrefixExpression, ImplKind,
+    HirMethodCallExpression, HirPrefixExpression, HirSubArrayExpression, ImplKind,
 };
 
 use crate::hir_def::traits::{Trait, TraitConstraint};
@@ -38,10 +38,10 @@ use crate::{
 };
 use crate::{
     ArrayLiteral, BinaryOpKind, Distinctness, ForRange, FunctionDefinition, FunctionReturnType,
-    Generics, ItemVisibility, LValue, NoirStruct, NoirTypeAlias, Param, Path, PathKind, Pattern,
-    Shared, Statement, StructType, Type, TypeAlias, TypeVariable, TypeVariableKind, UnaryOp,
-    UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData,
-    UnresolvedTypeExpression, Visibility, ERROR_IDENT,
+    Generics, IntegerBitSize, ItemVisibility, LValue, NoirStruct, NoirTypeAlias, Param, Path,
+    PathKind, Pattern, Shared, Signedness, Statement, StructType, Type, TypeAlias, TypeVariable,
+    TypeVariableKind, UnaryOp, UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType,
+    UnresolvedTypeData, UnresolvedTypeExpression, Visibility, ERROR_IDENT,
 };
 use fm::FileId;
 use iter_extended::vecmap;
@@ -258,6 +258,7 @@ impl<'a> Resolver<'a> {
             return_type: return_type.clone(),
             return_visibility: Visibility::Private,
             return_distinctness: Distinctness::DuplicationAllowed,
+            doc_comments: Vec::new(),
         };
 
         let (hir_func, func_meta) = self.intern_function(NoirFunction { kind, def }, func_id);
@@ -924,7 +925,7 @@ impl<'a> Resolver<'a> {
                 });
             }
 
-            let pattern = self.resolve_pattern(pattern, DefinitionKind::Local(None));
+            let pattern = self.resolve_parameter_pattern(pattern);
             let typ = self.resolve_type_inner(typ, &mut generics);
 
             parameters.push((pattern, typ.clone(), visibility));
@@ -1202,6 +1203,7 @@ impl<'a> Resolver<'a> {
             StatementKind::For(for_loop) => {
                 match for_loop.range {
                     ForRange::Range(start_range, end_range) => {
+                        self.check_range_bounds(&start_range, &end_range);
                         let start_range = self.resolve_expression(start_range);
                         let end_range = self.resolve_expression(end_range);
                         let (identifier, block) = (for_loop.identifier, for_loop.block);
@@ -1236,6 +1238,7 @@ impl<'a> Resolver<'a> {
                     }
                 }
             }
+            StatementKind::Function(function) => self.resolve_stmt(function.into_let(), span),
             StatementKind::Break => {
                 self.check_break_continue(true, span);
                 HirStatement::Break
@@ -1430,6 +1433,14 @@ impl<'a> Resolver<'a> {
 
     pub fn resolve_expression(&mut self, expr: Expression) -> ExprId {
         let hir_expr = match expr.kind {
+            // A character literal is a `u8` holding the character's ASCII value
+            ExpressionKind::Literal(Literal::Char(c)) => {
+                let value = HirExpression::Literal(HirLiteral::Integer((c as u128).into(), false));
+                let lhs = self.interner.push_expr(value);
+                self.interner.push_expr_location(lhs, expr.span, self.file);
+                let r#type = Type::Integer(Signedness::Unsigned, IntegerBitSize::Eight);
+                HirExpression::Cast(HirCastExpression { lhs, r#type })
+            }
             ExpressionKind::Literal(literal) => HirExpression::Literal(match literal {
                 Literal::Bool(b) => HirLiteral::Bool(b),
                 Literal::Array(array_literal) => {
@@ -1439,6 +1450,7 @@ impl<'a> Resolver<'a> {
                     HirLiteral::Slice(self.resolve_array_literal(array_literal))
                 }
                 Literal::Integer(integer, sign) => HirLiteral::Integer(integer, sign),
+                Literal::Char(_) => unreachable!("Character literals are resolved to casts above"),
                 Literal::Str(str) => HirLiteral::Str(str),
                 Literal::RawStr(str, _) => HirLiteral::Str(str),
                 Literal::FmtStr(str) => self.resolve_fmt_str_literal(str, expr.span),
@@ -1557,6 +1569,11 @@ impl<'a> Resolver<'a> {
                 collection: self.resolve_expression(indexed_expr.collection),
                 index: self.resolve_expression(indexed_expr.index),
             }),
+            ExpressionKind::SubArray(sub_array) => HirExpression::SubArray(HirSubArrayExpression {
+                collection: self.resolve_expression(sub_array.collection),
+                start: sub_array.start,
+                end: sub_array.end,
+            }),
             ExpressionKind::Block(block_expr) => self.resolve_block(block_expr),
             ExpressionKind::Constructor(constructor) => {
                 let span = constructor.type_name.span();
@@ -1604,8 +1621,7 @@ impl<'a> Resolver<'a> {
                 this.lambda_stack.push(LambdaContext { captures: Vec::new(), scope_index });
 
                 let parameters = vecmap(lambda.parameters, |(pattern, typ)| {
-                    let parameter = DefinitionKind::Local(None);
-                    (this.resolve_pattern(pattern, parameter), this.resolve_inferred_type(typ))
+                    (this.resolve_parameter_pattern(pattern), this.resolve_inferred_type(typ))
                 });
 
                 let return_type = this.resolve_inferred_type(lambda.return_type);
@@ -1634,7 +1650,13 @@ impl<'a> Resolver<'a> {
     }
 
     fn resolve_pattern(&mut self, pattern: Pattern, definition: DefinitionKind) -> HirPattern {
-        self.resolve_pattern_mutable(pattern, None, definition)
+        self.resolve_pattern_mutable(pattern, None, definition, true)
+    }
+
+    /// Resolves the pattern of a function or lambda parameter. Unlike `let` bindings,
+    /// parameters may not shadow one another, so declaring the same name twice is an error.
+    fn resolve_parameter_pattern(&mut self, pattern: Pattern) -> HirPattern {
+        self.resolve_pattern_mutable(pattern, None, DefinitionKind::Local(None), false)
     }
 
     fn resolve_pattern_mutable(
@@ -1642,6 +1664,7 @@ impl<'a> Resolver<'a> {
         pattern: Pattern,
         mutable: Option<Span>,
         definition: DefinitionKind,
+        allow_shadowing: bool,
     ) -> HirPattern {
         match pattern {
             Pattern::Identifier(name) => {
@@ -1651,7 +1674,10 @@ impl<'a> Resolver<'a> {
                     (Some(_), DefinitionKind::Local(_)) => DefinitionKind::Local(None),
                     (_, other) => other,
                 };
-                let id = self.add_variable_decl(name, mutable.is_some(), true, definition);
+                // Any number of parameters may be ignored with `_`
+                let allow_shadowing = allow_shadowing || name.0.contents == "_";
+                let id =
+                    self.add_variable_decl(name, mutable.is_some(), allow_shadowing, definition);
                 HirPattern::Identifier(id)
             }
             Pattern::Mutable(pattern, span, _) => {
@@ -1659,13 +1685,19 @@ impl<'a> Resolver<'a> {
                     self.push_err(ResolverError::UnnecessaryMut { first_mut, second_mut: span });
                 }
 
-                let pattern = self.resolve_pattern_mutable(*pattern, Some(span), definition);
+                let pattern =
+                    self.resolve_pattern_mutable(*pattern, Some(span), definition, allow_shadowing);
                 let location = Location::new(span, self.file);
                 HirPattern::Mutable(Box::new(pattern), location)
             }
             Pattern::Tuple(fields, span) => {
                 let fields = vecmap(fields, |field| {
-                    self.resolve_pattern_mutable(field, mutable, definition.clone())
+                    self.resolve_pattern_mutable(
+                        field,
+                        mutable,
+                        definition.clone(),
+                        allow_shadowing,
+                    )
                 });
                 let location = Location::new(span, self.file);
                 HirPattern::Tuple(fields, location)
@@ -1690,7 +1722,12 @@ impl<'a> Resolver<'a> {
                 };
 
                 let resolve_field = |this: &mut Self, pattern| {
-                    this.resolve_pattern_mutable(pattern, mutable, definition.clone())
+                    this.resolve_pattern_mutable(
+                        pattern,
+                        mutable,
+                        definition.clone(),
+                        allow_shadowing,
+                    )
                 };
 
                 let typ = struct_type.clone();
@@ -2039,12 +2076,75 @@ impl<'a> Resolver<'a> {
                     BinaryOpKind::GreaterEqual => Ok((lhs >= rhs) as u128),
                     BinaryOpKind::And => Ok(lhs & rhs),
                     BinaryOpKind::Or => Ok(lhs | rhs),
+                    BinaryOpKind::LogicalAnd => Ok((lhs != 0 && rhs != 0) as u128),
+                    BinaryOpKind::LogicalOr => Ok((lhs != 0 || rhs != 0) as u128),
                     BinaryOpKind::Xor => Ok(lhs ^ rhs),
                     BinaryOpKind::ShiftRight => Ok(lhs >> rhs),
                     BinaryOpKind::ShiftLeft => Ok(lhs << rhs),
                     BinaryOpKind::Modulo => Ok(lhs % rhs),
                 }
             }
+            // `len` on an array of known length is the only builtin call we can fold here
+            HirExpression::MethodCall(method_call)
+                if method_call.method.0.contents == "len" && method_call.arguments.is_empty() =>
+            {
+                let object = method_call.object;
+                let length = self.try_eval_array_literal_length(object, span, fuel - 1)?;
+                if self.is_builtin_array_len(length) {
+                    Ok(length)
+                } else {
+                    Err(Some(ResolverError::InvalidArrayLengthExpr { span }))
+                }
+            }
+            _other => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
+        }
+    }
+
+    /// Returns true if calling `len` on an array of the given length resolves to the
+    /// `array_len` builtin rather than to a user-defined method of the same name.
+    fn is_builtin_array_len(&self, length: u128) -> bool {
+        let element_type = self.interner.next_type_variable();
+        let array_type =
+            Type::Array(Box::new(Type::Constant(length as u64)), Box::new(element_type));
+
+        self.interner.lookup_primitive_method(&array_type, "len").map_or(false, |method| {
+            let attribute = &self.interner.function_attributes(&method).function;
+            matches!(attribute, Some(FunctionAttribute::Builtin(name)) if name == "array_len")
+        })
+    }
+
+    /// Returns the length of the array literal `array`, following any globals it refers to.
+    fn try_eval_array_literal_length(
+        &self,
+        array: ExprId,
+        span: Span,
+        fuel: u32,
+    ) -> Result<u128, Option<ResolverError>> {
+        if fuel == 0 {
+            return Err(None);
+        }
+
+        match self.interner.expression(&array) {
+            HirExpression::Literal(HirLiteral::Array(HirArrayLiteral::Standard(elements))) => {
+                Ok(elements.len() as u128)
+            }
+            HirExpression::Literal(HirLiteral::Array(HirArrayLiteral::Repeated {
+                length: Type::Constant(length),
+                ..
+            })) => Ok(length as u128),
+            HirExpression::Ident(ident) => match self.interner.definition(ident.id).kind {
+                DefinitionKind::Global(global_id) => {
+                    match self.interner.get_global_let_statement(global_id) {
+                        Some(let_statement) => self.try_eval_array_literal_length(
+                            let_statement.expression,
+                            span,
+                            fuel - 1,
+                        ),
+                        None => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
+                    }
+                }
+                _ => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
+            },
             _other => Err(Some(ResolverError::InvalidArrayLengthExpr { span })),
         }
     }
@@ -2080,6 +2180,30 @@ impl<'a> Resolver<'a> {
         HirLiteral::FmtStr(str, fmt_str_idents)
     }
 
+    /// Gives an error if both bounds of a range are integer literals and its start exceeds its end.
+    fn check_range_bounds(&mut self, start: &Expression, end: &Expression) {
+        let (
+            ExpressionKind::Literal(Literal::Integer(start_value, false)),
+            ExpressionKind::Literal(Literal::Integer(end_value, false)),
+        ) = (&start.kind, &end.kind)
+        else {
+            return;
+        };
+
+        if let (Some(start_value), Some(end_value)) =
+            (start_value.try_into_u128(), end_value.try_into_u128())
+        {
+            if start_value > end_value {
+                let span = start.span.merge(end.span);
+                self.push_err(ResolverError::ReversedRange {
+                    start: start_value,
+                    end: end_value,
+                    span,
+                });
+            }
+        }
+    }
+
     fn check_break_continue(&mut self, is_break: bool, span: Span) {
         if !self.in_unconstrained_fn {
             self.push_err(ResolverError::JumpInConstrainedFn { is_break, span });
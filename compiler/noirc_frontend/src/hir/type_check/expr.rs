@@ -171,6 +171,9 @@ impl<'interner> TypeChecker<'interner> {
                 }
             }
             HirExpression::Index(index_expr) => self.check_index_expression(expr_id, index_expr),
+            HirExpression::SubArray(sub_array) => {
+                self.check_sub_array_expression(expr_id, sub_array)
+            }
             HirExpression::Call(call_expr) => {
                 // Need to setup these flags here as `self` is borrowed mutably to type check the rest of the call expression
                 // These flags are later used to type check calls to unconstrained functions from constrained functions
@@ -593,6 +596,35 @@ impl<'interner> TypeChecker<'interner> {
         }
     }
 
+    fn check_sub_array_expression(
+        &mut self,
+        id: &ExprId,
+        mut sub_array: expr::HirSubArrayExpression,
+    ) -> Type {
+        // As with indexing, `a[start..end]` automatically dereferences `a` if needed.
+        let lhs_type = self.check_expression(&sub_array.collection);
+        let (new_lhs, lhs_type) = self.insert_auto_dereferences(sub_array.collection, lhs_type);
+        let length = sub_array.end.saturating_sub(sub_array.start);
+        sub_array.collection = new_lhs;
+        self.interner.replace_expr(id, HirExpression::SubArray(sub_array));
+
+        // The bounds are checked against the array's length during monomorphization, once any
+        // generic lengths are known.
+        match lhs_type.follow_bindings() {
+            Type::Array(_, base_type) => Type::Array(Box::new(Type::Constant(length)), base_type),
+            Type::Error => Type::Error,
+            typ => {
+                let span = self.interner.expr_span(&new_lhs);
+                self.errors.push(TypeCheckError::TypeMismatch {
+                    expected_typ: "Array".to_owned(),
+                    expr_typ: typ.to_string(),
+                    expr_span: span,
+                });
+                Type::Error
+            }
+        }
+    }
+
     fn check_cast(&mut self, from: Type, to: Type, span: Span) -> Type {
         match from.follow_bindings() {
             Type::Integer(..)
@@ -1126,6 +1158,18 @@ impl<'interner> TypeChecker<'interner> {
             return self.comparator_operand_type_rules(lhs_type, rhs_type, op, span);
         }
 
+        // Logical operators are never overloaded and only operate on booleans
+        if op.kind.is_logical() {
+            for typ in [lhs_type, rhs_type] {
+                typ.unify(&Type::Bool, &mut self.errors, || TypeCheckError::TypeMismatch {
+                    expected_typ: Type::Bool.to_string(),
+                    expr_typ: typ.to_string(),
+                    expr_span: span,
+                });
+            }
+            return Ok((Type::Bool, false));
+        }
+
         use Type::*;
         match (lhs_type, rhs_type) {
             // An error type on either side will always return an error
@@ -1228,6 +1272,15 @@ impl<'interner> TypeChecker<'interner> {
 
                 unify(Type::Bool)
             }
+            crate::UnaryOp::BitNot => {
+                // `~` only works on integers
+                let expected = Type::polymorphic_integer(self.interner);
+                rhs_type.unify(&expected, &mut self.errors, || TypeCheckError::InvalidUnaryOp {
+                    kind: rhs_type.to_string(),
+                    span,
+                });
+                expected
+            }
             crate::UnaryOp::MutableReference => {
                 Type::MutableReference(Box::new(rhs_type.follow_bindings()))
             }
@@ -57,6 +57,8 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<Type
     let expected_return_type = meta.return_type.clone();
     let expected_trait_constraints = meta.trait_constraints.clone();
     let name_span = meta.name.location.span;
+    let is_entry_point = meta.is_entry_point;
+    let return_type_span = meta.return_type.get_type().span.unwrap_or(name_span);
 
     let mut errors = Vec::new();
 
@@ -84,6 +86,11 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<Type
         type_checker.bind_pattern(&param.0, param.1);
     }
 
+    // The return type of an entry point must also be representable in the program's ABI.
+    if is_entry_point && !declared_return_type.is_valid_for_program_input() {
+        errors.push(TypeCheckError::InvalidTypeForEntryPoint { span: return_type_span });
+    }
+
     let function_last_type = type_checker.check_function_body(function_body_id);
 
     // Verify any remaining trait constraints arising from the function body
@@ -22,6 +22,7 @@ pub enum HirExpression {
     Prefix(HirPrefixExpression),
     Infix(HirInfixExpression),
     Index(HirIndexExpression),
+    SubArray(HirSubArrayExpression),
     Constructor(HirConstructorExpression),
     MemberAccess(HirMemberAccess),
     Call(HirCallExpression),
@@ -248,6 +249,14 @@ pub struct HirIndexExpression {
     pub index: ExprId,
 }
 
+/// A sub-array taken with constant bounds, as in `array[start..end]`
+#[derive(Debug, Clone)]
+pub struct HirSubArrayExpression {
+    pub collection: ExprId,
+    pub start: u64,
+    pub end: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct HirBlockExpression(pub Vec<StmtId>);
 
@@ -17,8 +17,6 @@ pub enum LexerErrorKind {
     InvalidIntegerLiteral { span: Span, found: String },
     #[error("{:?} is not a valid attribute", found)]
     MalformedFuncAttribute { span: Span, found: String },
-    #[error("Logical and used instead of bitwise and")]
-    LogicalAnd { span: Span },
     #[error("Unterminated block comment")]
     UnterminatedBlockComment { span: Span },
     #[error("Unterminated string literal")]
@@ -27,6 +25,10 @@ pub enum LexerErrorKind {
         "'\\{escaped}' is not a valid escape sequence. Use '\\' for a literal backslash character."
     )]
     InvalidEscape { escaped: char, span: Span },
+    #[error("Character literals must contain exactly one character")]
+    InvalidCharLiteral { span: Span },
+    #[error("{:?} is not an ASCII character", found)]
+    NonAsciiCharLiteral { found: char, span: Span },
 }
 
 impl From<LexerErrorKind> for ParserError {
@@ -43,10 +45,11 @@ impl LexerErrorKind {
             LexerErrorKind::NotADoubleChar { span, .. } => *span,
             LexerErrorKind::InvalidIntegerLiteral { span, .. } => *span,
             LexerErrorKind::MalformedFuncAttribute { span, .. } => *span,
-            LexerErrorKind::LogicalAnd { span } => *span,
             LexerErrorKind::UnterminatedBlockComment { span } => *span,
             LexerErrorKind::UnterminatedStringLiteral { span } => *span,
             LexerErrorKind::InvalidEscape { span, .. } => *span,
+            LexerErrorKind::InvalidCharLiteral { span } => *span,
+            LexerErrorKind::NonAsciiCharLiteral { span, .. } => *span,
         }
     }
 
@@ -82,16 +85,15 @@ impl LexerErrorKind {
                 format!(" {found} is not a valid attribute"),
                 *span,
             ),
-            LexerErrorKind::LogicalAnd { span } => (
-                "Noir has no logical-and (&&) operator since short-circuiting is much less efficient when compiling to circuits".to_string(),
-                "Try `&` instead, or use `if` only if you require short-circuiting".to_string(),
-                *span,
-            ),
             LexerErrorKind::UnterminatedBlockComment { span } => ("Unterminated block comment".to_string(), "Unterminated block comment".to_string(), *span),
             LexerErrorKind::UnterminatedStringLiteral { span } =>
                 ("Unterminated string literal".to_string(), "Unterminated string literal".to_string(), *span),
             LexerErrorKind::InvalidEscape { escaped, span } =>
                 (format!("'\\{escaped}' is not a valid escape sequence. Use '\\' for a literal backslash character."), "Invalid escape sequence".to_string(), *span),
+            LexerErrorKind::InvalidCharLiteral { span } =>
+                ("Invalid character literal".to_string(), "Character literals must contain exactly one character".to_string(), *span),
+            LexerErrorKind::NonAsciiCharLiteral { found, span } =>
+                (format!("{found:?} is not an ASCII character"), "Only ASCII characters can be used in character literals".to_string(), *span),
         }
     }
 }
@@ -17,6 +17,7 @@ pub struct Lexer<'a> {
     done: bool,
     skip_comments: bool,
     skip_whitespaces: bool,
+    emit_doc_comments: bool,
 }
 
 pub type SpannedTokenResult = Result<SpannedToken, LexerErrorKind>;
@@ -25,10 +26,19 @@ impl<'a> Lexer<'a> {
     /// Given a source file of noir code, return all the tokens in the file
     /// in order, along with any lexing errors that occurred.
     pub fn lex(source: &'a str) -> (Tokens, Vec<LexerErrorKind>) {
-        let lexer = Lexer::new(source);
+        Lexer::new(source).collect_tokens()
+    }
+
+    /// Like [Lexer::lex] but keeps the outer doc comments (`///` and `/** */`) in the returned
+    /// tokens, for tooling which needs to know what they document.
+    pub fn lex_with_doc_comments(source: &'a str) -> (Tokens, Vec<LexerErrorKind>) {
+        Lexer::new(source).emit_doc_comments(true).collect_tokens()
+    }
+
+    fn collect_tokens(self) -> (Tokens, Vec<LexerErrorKind>) {
         let mut tokens = vec![];
         let mut errors = vec![];
-        for result in lexer {
+        for result in self {
             match result {
                 Ok(token) => tokens.push(token),
                 Err(error) => errors.push(error),
@@ -44,6 +54,7 @@ impl<'a> Lexer<'a> {
             done: false,
             skip_comments: true,
             skip_whitespaces: true,
+            emit_doc_comments: false,
         }
     }
 
@@ -57,6 +68,17 @@ impl<'a> Lexer<'a> {
         self
     }
 
+    /// When set, outer doc comments (`///` and `/** */`) are emitted even if other comments are
+    /// skipped.
+    pub fn emit_doc_comments(mut self, flag: bool) -> Self {
+        self.emit_doc_comments = flag;
+        self
+    }
+
+    fn skips_comment(&self, doc_style: Option<DocStyle>) -> bool {
+        self.skip_comments && !(self.emit_doc_comments && doc_style == Some(DocStyle::Outer))
+    }
+
     /// Iterates the cursor and returns the char at the new cursor position
     fn next_char(&mut self) -> Option<char> {
         let (position, ch) = self.chars.next()?;
@@ -81,17 +103,6 @@ impl<'a> Lexer<'a> {
         self.peek_char() == Some(ch)
     }
 
-    fn ampersand(&mut self) -> SpannedTokenResult {
-        if self.peek_char_is('&') {
-            // When we issue this error the first '&' will already be consumed
-            // and the next token issued will be the next '&'.
-            let span = Span::inclusive(self.position, self.position + 1);
-            Err(LexerErrorKind::LogicalAnd { span })
-        } else {
-            self.single_char_token(Token::Ampersand)
-        }
-    }
-
     fn next_token(&mut self) -> SpannedTokenResult {
         match self.next_char() {
             Some(x) if x.is_whitespace() => {
@@ -110,10 +121,11 @@ impl<'a> Lexer<'a> {
             Some(':') => self.glue(Token::Colon),
             Some('!') => self.glue(Token::Bang),
             Some('-') => self.glue(Token::Minus),
-            Some('&') => self.ampersand(),
-            Some('|') => self.single_char_token(Token::Pipe),
+            Some('&') => self.single_double_peek_token('&', Token::Ampersand, Token::LogicalAnd),
+            Some('|') => self.single_double_peek_token('|', Token::Pipe, Token::LogicalOr),
             Some('%') => self.single_char_token(Token::Percent),
             Some('^') => self.single_char_token(Token::Caret),
+            Some('~') => self.single_char_token(Token::Tilde),
             Some(';') => self.single_char_token(Token::Semicolon),
             Some('*') => self.single_char_token(Token::Star),
             Some('(') => self.single_char_token(Token::LeftParen),
@@ -125,6 +137,7 @@ impl<'a> Lexer<'a> {
             Some('[') => self.single_char_token(Token::LeftBracket),
             Some(']') => self.single_char_token(Token::RightBracket),
             Some('"') => self.eat_string_literal(),
+            Some('\'') => self.eat_char_literal(),
             Some('f') => self.eat_format_string_or_alpha_numeric(),
             Some('r') => self.eat_raw_string_or_alpha_numeric(),
             Some('#') => self.eat_attribute(),
@@ -395,6 +408,46 @@ impl<'a> Lexer<'a> {
         Ok(str_literal_token.into_span(start, end))
     }
 
+    fn eat_char_literal(&mut self) -> SpannedTokenResult {
+        let start = self.position;
+
+        let char = match self.next_char() {
+            Some('\\') => match self.next_char() {
+                Some('r') => '\r',
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('0') => '\0',
+                Some('\'') => '\'',
+                Some('\\') => '\\',
+                Some(escaped) => {
+                    let span = Span::inclusive(start, self.position);
+                    return Err(LexerErrorKind::InvalidEscape { escaped, span });
+                }
+                None => {
+                    let span = Span::inclusive(start, self.position);
+                    return Err(LexerErrorKind::InvalidCharLiteral { span });
+                }
+            },
+            Some(char) if char != '\'' => char,
+            _ => {
+                let span = Span::inclusive(start, self.position);
+                return Err(LexerErrorKind::InvalidCharLiteral { span });
+            }
+        };
+
+        if self.next_char() != Some('\'') {
+            let span = Span::inclusive(start, self.position);
+            return Err(LexerErrorKind::InvalidCharLiteral { span });
+        }
+
+        let span = Span::inclusive(start, self.position);
+        if !char.is_ascii() {
+            return Err(LexerErrorKind::NonAsciiCharLiteral { found: char, span });
+        }
+
+        Ok(Token::Char(char).into_span(start, self.position))
+    }
+
     // This differs from `eat_string_literal` in that we want the leading `f` to be captured in the Span
     fn eat_fmt_string(&mut self) -> SpannedTokenResult {
         let start = self.position;
@@ -505,7 +558,7 @@ impl<'a> Lexer<'a> {
         };
         let comment = self.eat_while(None, |ch| ch != '\n');
 
-        if self.skip_comments {
+        if self.skips_comment(doc_style) {
             return self.next_token();
         }
 
@@ -550,7 +603,7 @@ impl<'a> Lexer<'a> {
         }
 
         if depth == 0 {
-            if self.skip_comments {
+            if self.skips_comment(doc_style) {
                 return self.next_token();
             }
             Ok(Token::BlockComment(content, doc_style).into_span(start, self.position))
@@ -584,7 +637,7 @@ mod tests {
     use crate::token::{FunctionAttribute, SecondaryAttribute, TestScope};
     #[test]
     fn test_single_double_char() {
-        let input = "! != + ( ) { } [ ] | , ; : :: < <= > >= & - -> . .. % / * = == << >>";
+        let input = "! != + ( ) { } [ ] | || , ; : :: < <= > >= & && - -> . .. % / * = == << >>";
 
         let expected = vec![
             Token::Bang,
@@ -597,6 +650,7 @@ mod tests {
             Token::LeftBracket,
             Token::RightBracket,
             Token::Pipe,
+            Token::LogicalOr,
             Token::Comma,
             Token::Semicolon,
             Token::Colon,
@@ -606,6 +660,7 @@ mod tests {
             Token::Greater,
             Token::GreaterEqual,
             Token::Ampersand,
+            Token::LogicalAnd,
             Token::Minus,
             Token::Arrow,
             Token::Dot,
@@ -908,6 +963,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn emits_outer_doc_comments_when_skipping_comments() {
+        let input = "
+            // comment
+            /// comment
+            //! comment
+            /* comment */
+            /** outer doc block */
+            /*! inner doc block */
+            fn
+        ";
+        let expected = [
+            Token::LineComment(" comment".into(), DocStyle::Outer.into()),
+            Token::BlockComment(" outer doc block ".into(), DocStyle::Outer.into()),
+            Token::Keyword(Keyword::Fn),
+            Token::EOF,
+        ];
+
+        let mut lexer = Lexer::new(input).emit_doc_comments(true);
+        for token in expected {
+            let first_lexer_output = lexer.next_token().unwrap();
+            assert_eq!(token, first_lexer_output);
+        }
+
+        let mut lexer = Lexer::new(input);
+        assert_eq!(lexer.next_token().unwrap(), Token::Keyword(Keyword::Fn));
+    }
+
     #[test]
     fn test_nested_block_comments() {
         let input = "
@@ -947,6 +1030,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_eat_char_literal() {
+        let input = "'A' '\\n' '\\''";
+
+        let expected = vec![Token::Char('A'), Token::Char('\n'), Token::Char('\'')];
+        let mut lexer = Lexer::new(input);
+
+        for token in expected.into_iter() {
+            let got = lexer.next_token().unwrap();
+            assert_eq!(got, token);
+        }
+    }
+
+    #[test]
+    fn test_invalid_char_literals() {
+        for input in ["''", "'ab'", "'a", "'é'"] {
+            assert!(Lexer::new(input).next_token().is_err(), "expected {input} to fail to lex");
+        }
+        assert!(matches!(
+            Lexer::new("'é'").next_token(),
+            Err(LexerErrorKind::NonAsciiCharLiteral { found: 'é', .. })
+        ));
+    }
+
     #[test]
     fn test_eat_integer_literals() {
         let test_cases: Vec<(&str, Token)> = vec![
@@ -955,6 +1062,11 @@ mod tests {
             ("0x1234_5678", Token::Int(0x1234_5678_u128.into())),
             ("0x_01", Token::Int(0x1_u128.into())),
             ("1_000_000", Token::Int(1_000_000_u128.into())),
+            // Hex literals may be as large as a field element
+            (
+                "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000",
+                Token::Int(-FieldElement::one()),
+            ),
         ];
 
         for (input, expected_token) in test_cases {
@@ -13,6 +13,8 @@ use crate::lexer::errors::LexerErrorKind;
 pub enum Token {
     Ident(String),
     Int(FieldElement),
+    /// A character literal such as `'A'`. Only ASCII characters are allowed.
+    Char(char),
     Bool(bool),
     Str(String),
     RawStr(String, u8),
@@ -46,6 +48,8 @@ pub enum Token {
     Percent,
     /// &
     Ampersand,
+    /// &&
+    LogicalAnd,
     /// ^
     Caret,
     /// <<
@@ -72,6 +76,8 @@ pub enum Token {
     Arrow,
     /// |
     Pipe,
+    /// ||
+    LogicalOr,
     /// #
     Pound,
     /// ,
@@ -84,6 +90,8 @@ pub enum Token {
     Semicolon,
     /// !
     Bang,
+    /// ~
+    Tilde,
     /// =
     Assign,
     #[allow(clippy::upper_case_acronyms)]
@@ -155,6 +163,7 @@ impl fmt::Display for Token {
         match *self {
             Token::Ident(ref s) => write!(f, "{s}"),
             Token::Int(n) => write!(f, "{}", n.to_u128()),
+            Token::Char(c) => write!(f, "'{}'", c.escape_default()),
             Token::Bool(b) => write!(f, "{b}"),
             Token::Str(ref b) => write!(f, "{b}"),
             Token::FmtStr(ref b) => write!(f, "f{b}"),
@@ -179,6 +188,7 @@ impl fmt::Display for Token {
             Token::Slash => write!(f, "/"),
             Token::Percent => write!(f, "%"),
             Token::Ampersand => write!(f, "&"),
+            Token::LogicalAnd => write!(f, "&&"),
             Token::Caret => write!(f, "^"),
             Token::ShiftLeft => write!(f, "<<"),
             Token::ShiftRight => write!(f, ">>"),
@@ -192,6 +202,7 @@ impl fmt::Display for Token {
             Token::RightBracket => write!(f, "]"),
             Token::Arrow => write!(f, "->"),
             Token::Pipe => write!(f, "|"),
+            Token::LogicalOr => write!(f, "||"),
             Token::Pound => write!(f, "#"),
             Token::Comma => write!(f, ","),
             Token::Colon => write!(f, ":"),
@@ -199,6 +210,7 @@ impl fmt::Display for Token {
             Token::Semicolon => write!(f, ";"),
             Token::Assign => write!(f, "="),
             Token::Bang => write!(f, "!"),
+            Token::Tilde => write!(f, "~"),
             Token::EOF => write!(f, "end of input"),
             Token::Invalid(c) => write!(f, "{c}"),
             Token::Whitespace(ref s) => write!(f, "{s}"),
@@ -233,6 +245,7 @@ impl Token {
         match *self {
             Token::Ident(_) => TokenKind::Ident,
             Token::Int(_)
+            | Token::Char(_)
             | Token::Bool(_)
             | Token::Str(_)
             | Token::RawStr(..)
@@ -271,6 +284,8 @@ impl Token {
             Token::ShiftLeft => ShiftLeft,
             Token::ShiftRight => ShiftRight,
             Token::Pipe => Or,
+            Token::LogicalAnd => LogicalAnd,
+            Token::LogicalOr => LogicalOr,
             Token::Minus => Subtract,
             Token::Star => Multiply,
             Token::Slash => Divide,
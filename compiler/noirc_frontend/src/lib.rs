@@ -26,7 +26,7 @@ pub mod hir_def;
 pub use lexer::token;
 
 // Parser API
-pub use parser::{parse_program, ParsedModule};
+pub use parser::{parse_program, parse_program_with_doc_comments, ParsedModule};
 
 // AST API
 pub use ast::*;
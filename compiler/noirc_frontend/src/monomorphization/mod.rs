@@ -92,12 +92,15 @@ type HirType = crate::Type;
 pub enum MonomorphizationError {
     #[error("Length of generic array could not be determined.")]
     UnknownArrayLength { location: Location },
+    #[error("Range {start}..{end} is out of bounds for an array of length {length}")]
+    SubArrayOutOfBounds { start: u64, end: u64, length: u64, location: Location },
 }
 
 impl MonomorphizationError {
     fn call_stack(&self) -> Vec<Location> {
         match self {
-            MonomorphizationError::UnknownArrayLength { location } => vec![*location],
+            MonomorphizationError::UnknownArrayLength { location }
+            | MonomorphizationError::SubArrayOutOfBounds { location, .. } => vec![*location],
         }
     }
 }
@@ -113,12 +116,18 @@ impl From<MonomorphizationError> for FileDiagnostic {
 
 impl MonomorphizationError {
     fn into_diagnostic(self) -> CustomDiagnostic {
-        CustomDiagnostic::simple_error(
+        match self {
+            MonomorphizationError::SubArrayOutOfBounds { location, .. } => {
+                let message = self.to_string();
+                CustomDiagnostic::simple_error(message, String::new(), location.span)
+            }
+            MonomorphizationError::UnknownArrayLength { .. } => CustomDiagnostic::simple_error(
                 "Internal Consistency Evaluators Errors: \n
                 This is likely a bug. Consider opening an issue at https://github.com/noir-lang/noir/issues".to_owned(),
                 self.to_string(),
                 noirc_errors::Span::inclusive(0, 0)
-            )
+            ),
+        }
     }
 }
 
@@ -476,6 +485,7 @@ impl<'interner> Monomorphizer<'interner> {
             }
 
             HirExpression::Index(index) => self.index(expr, index)?,
+            HirExpression::SubArray(sub_array) => self.sub_array(expr, sub_array)?,
 
             HirExpression::MemberAccess(access) => {
                 let field_index = self.interner.get_field_index(expr);
@@ -572,6 +582,64 @@ impl<'interner> Monomorphizer<'interner> {
         Ok(ast::Expression::Index(ast::Index { collection, index, element_type, location }))
     }
 
+    /// Lowers `collection[start..end]` into a block which evaluates `collection` once and
+    /// builds a new array from each of the indexed elements:
+    ///
+    /// ```text
+    /// {
+    ///     let sub_array_collection = collection;
+    ///     [sub_array_collection[start], ..., sub_array_collection[end - 1]]
+    /// }
+    /// ```
+    fn sub_array(
+        &mut self,
+        id: node_interner::ExprId,
+        sub_array: HirSubArrayExpression,
+    ) -> Result<ast::Expression, MonomorphizationError> {
+        let location = self.interner.expr_location(&id);
+        let collection_type = self.convert_type(&self.interner.id_type(sub_array.collection));
+        let ast::Type::Array(length, element_type) = collection_type.clone() else {
+            unreachable!("Sub-array expressions are only type checked against arrays")
+        };
+
+        let HirSubArrayExpression { start, end, .. } = sub_array;
+        if end > length {
+            let error = MonomorphizationError::SubArrayOutOfBounds { start, end, length, location };
+            return Err(error);
+        }
+
+        let collection_id = self.next_local_id();
+        let name = "sub_array_collection".to_string();
+        let collection = ast::Expression::Let(ast::Let {
+            id: collection_id,
+            mutable: false,
+            name: name.clone(),
+            expression: Box::new(self.expr(sub_array.collection)?),
+        });
+
+        let collection_ident = ast::Expression::Ident(ast::Ident {
+            location: Some(location),
+            definition: Definition::Local(collection_id),
+            mutable: false,
+            name,
+            typ: collection_type,
+        });
+
+        let contents = vecmap(start..end, |index| {
+            let index = ast::Literal::Integer((index as u128).into(), ast::Type::Field, location);
+            ast::Expression::Index(ast::Index {
+                collection: Box::new(collection_ident.clone()),
+                index: Box::new(ast::Expression::Literal(index)),
+                element_type: element_type.as_ref().clone(),
+                location,
+            })
+        });
+
+        let typ = self.convert_type(&self.interner.id_type(id));
+        let array = ast::Literal::Array(ast::ArrayLiteral { contents, typ });
+        Ok(ast::Expression::Block(vec![collection, ast::Expression::Literal(array)]))
+    }
+
     fn statement(&mut self, id: StmtId) -> Result<ast::Expression, MonomorphizationError> {
         match self.interner.statement(&id) {
             HirStatement::Let(let_statement) => self.let_statement(let_statement),
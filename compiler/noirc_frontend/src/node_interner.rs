@@ -1451,6 +1451,14 @@ impl NodeInterner {
             BinaryOpKind::Equal => {
                 self.operator_traits.insert(BinaryOpKind::NotEqual, trait_id);
             }
+            // The logical operators cannot be overloaded but still need a trait method id
+            // when resolved, so they share the one of their bitwise counterpart.
+            BinaryOpKind::And => {
+                self.operator_traits.insert(BinaryOpKind::LogicalAnd, trait_id);
+            }
+            BinaryOpKind::Or => {
+                self.operator_traits.insert(BinaryOpKind::LogicalOr, trait_id);
+            }
             BinaryOpKind::Less => {
                 self.operator_traits.insert(BinaryOpKind::LessEqual, trait_id);
                 self.operator_traits.insert(BinaryOpKind::Greater, trait_id);
@@ -1487,6 +1495,8 @@ impl NodeInterner {
         self.operator_traits.insert(BinaryOpKind::GreaterEqual, dummy_trait);
         self.operator_traits.insert(BinaryOpKind::And, dummy_trait);
         self.operator_traits.insert(BinaryOpKind::Or, dummy_trait);
+        self.operator_traits.insert(BinaryOpKind::LogicalAnd, dummy_trait);
+        self.operator_traits.insert(BinaryOpKind::LogicalOr, dummy_trait);
         self.operator_traits.insert(BinaryOpKind::Xor, dummy_trait);
         self.operator_traits.insert(BinaryOpKind::ShiftLeft, dummy_trait);
         self.operator_traits.insert(BinaryOpKind::ShiftRight, dummy_trait);
@@ -43,6 +43,8 @@ pub enum ParserErrorReason {
     AssertMessageNotString,
     #[error("Integer bit size {0} isn't supported")]
     InvalidBitSize(u32),
+    #[error("Array range start {0} is greater than its end {1}")]
+    InvalidSubArrayRange(u64, u64),
     #[error("{0}")]
     Lexer(LexerErrorKind),
 }
@@ -9,6 +9,7 @@ pub enum ParsingRuleLabel {
     Atom,
     BinaryOperator,
     Cast,
+    DocComment,
     Expression,
     FieldAccess,
     Global,
@@ -27,6 +28,7 @@ impl fmt::Display for ParsingRuleLabel {
             ParsingRuleLabel::Atom => write!(f, "atom"),
             ParsingRuleLabel::BinaryOperator => write!(f, "binary operator"),
             ParsingRuleLabel::Cast => write!(f, "cast"),
+            ParsingRuleLabel::DocComment => write!(f, "doc comment"),
             ParsingRuleLabel::Expression => write!(f, "expression"),
             ParsingRuleLabel::FieldAccess => write!(f, "field access"),
             ParsingRuleLabel::Global => write!(f, "global"),
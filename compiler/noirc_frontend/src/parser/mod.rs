@@ -23,7 +23,7 @@ use chumsky::primitive::Container;
 pub use errors::ParserError;
 pub use errors::ParserErrorReason;
 use noirc_errors::Span;
-pub use parser::parse_program;
+pub use parser::{parse_program, parse_program_with_doc_comments};
 
 #[derive(Debug, Clone)]
 pub(crate) enum TopLevelStatement {
@@ -396,6 +396,8 @@ impl SortedModule {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]
 pub enum Precedence {
     Lowest,
+    LogicalAnd,
+    Equality,
     Or,
     And,
     Xor,
@@ -411,8 +413,10 @@ impl Precedence {
     // XXX: Check the precedence is correct for operators
     fn token_precedence(tok: &Token) -> Option<Precedence> {
         let precedence = match tok {
-            Token::Equal => Precedence::Lowest,
-            Token::NotEqual => Precedence::Lowest,
+            Token::LogicalOr => Precedence::Lowest,
+            Token::LogicalAnd => Precedence::LogicalAnd,
+            Token::Equal => Precedence::Equality,
+            Token::NotEqual => Precedence::Equality,
             Token::Pipe => Precedence::Or,
             Token::Ampersand => Precedence::And,
             Token::Caret => Precedence::Xor,
@@ -438,7 +442,9 @@ impl Precedence {
     fn next(self) -> Self {
         use Precedence::*;
         match self {
-            Lowest => Or,
+            Lowest => LogicalAnd,
+            LogicalAnd => Equality,
+            Equality => Or,
             Or => Xor,
             Xor => And,
             And => LessGreater,
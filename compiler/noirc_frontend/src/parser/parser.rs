@@ -35,9 +35,9 @@ use super::{spanned, Item, ItemKind};
 use crate::ast::{
     Expression, ExpressionKind, LetStatement, StatementKind, UnresolvedType, UnresolvedTypeData,
 };
-use crate::lexer::Lexer;
+use crate::lexer::{errors::LexerErrorKind, Lexer};
 use crate::parser::{force, ignore_then_commit, statement_recovery};
-use crate::token::{Keyword, Token, TokenKind};
+use crate::token::{Keyword, Token, TokenKind, Tokens};
 use crate::{
     BinaryOp, BinaryOpKind, BlockExpression, Distinctness, ForLoopStatement, ForRange,
     FunctionReturnType, Ident, IfExpression, InfixExpression, LValue, Literal, ModuleDeclaration,
@@ -51,6 +51,7 @@ use noirc_errors::{Span, Spanned};
 
 mod assertion;
 mod attributes;
+mod doc_comments;
 mod function;
 mod lambdas;
 mod literals;
@@ -62,9 +63,12 @@ mod traits;
 #[cfg(test)]
 mod test_helpers;
 
+use doc_comments::outer_doc_comments;
 use literals::literal;
 use path::{maybe_empty_path, path};
-use primitives::{dereference, ident, negation, not, nothing, right_shift_operator, token_kind};
+use primitives::{
+    bit_not, dereference, ident, negation, not, nothing, right_shift_operator, token_kind,
+};
 
 /// Entry function for the parser - also handles lexing internally.
 ///
@@ -74,6 +78,24 @@ use primitives::{dereference, ident, negation, not, nothing, right_shift_operato
 /// failed to parse. Otherwise the Ast is guaranteed to have 0 Error nodes.
 pub fn parse_program(source_program: &str) -> (ParsedModule, Vec<ParserError>) {
     let (tokens, lexing_errors) = Lexer::lex(source_program);
+    parse_tokens(tokens, lexing_errors)
+}
+
+/// Parses `source_program` like [parse_program], additionally attaching the outer doc comments
+/// (`///` and `/** */`) which precede each function to its `FunctionDefinition`.
+///
+/// This is meant for tooling such as documentation generators. Unlike [parse_program], a doc
+/// comment which does not precede an item, struct field, trait item or statement is reported as
+/// an error.
+pub fn parse_program_with_doc_comments(source_program: &str) -> (ParsedModule, Vec<ParserError>) {
+    let (tokens, lexing_errors) = Lexer::lex_with_doc_comments(source_program);
+    parse_tokens(tokens, lexing_errors)
+}
+
+fn parse_tokens(
+    tokens: Tokens,
+    lexing_errors: Vec<LexerErrorKind>,
+) -> (ParsedModule, Vec<ParserError>) {
     let (module, mut parsing_errors) = program().parse_recovery_verbose(tokens);
 
     parsing_errors.extend(lexing_errors.into_iter().map(Into::into));
@@ -125,8 +147,8 @@ fn module() -> impl NoirParser<ParsedModule> {
 fn top_level_statement(
     module_parser: impl NoirParser<ParsedModule>,
 ) -> impl NoirParser<TopLevelStatement> {
-    choice((
-        function::function_definition(false).map(TopLevelStatement::Function),
+    // Only functions keep their doc comments, they are parsed and dropped for any other item.
+    let item = choice((
         structs::struct_definition(),
         traits::trait_definition(),
         traits::trait_implementation(),
@@ -137,6 +159,11 @@ fn top_level_statement(
         module_declaration().then_ignore(force(just(Token::Semicolon))),
         use_statement().then_ignore(force(just(Token::Semicolon))),
         global_declaration().then_ignore(force(just(Token::Semicolon))),
+    ));
+
+    choice((
+        function::function_definition(false).map(TopLevelStatement::Function),
+        outer_doc_comments().ignore_then(item),
     ))
     .recover_via(top_level_statement_recovery())
 }
@@ -334,10 +361,14 @@ fn block<'a>(
     statement: impl NoirParser<StatementKind> + 'a,
 ) -> impl NoirParser<BlockExpression> + 'a {
     use Token::*;
-    statement
+    let statement = statement
         .recover_via(statement_recovery())
         .then(just(Semicolon).or_not().map_with_span(|s, span| (s, span)))
-        .map_with_span(|(kind, rest), span| (Statement { kind, span }, rest))
+        .map_with_span(|(kind, rest), span| (Statement { kind, span }, rest));
+
+    // Doc comments on statements are accepted but discarded
+    outer_doc_comments()
+        .ignore_then(statement)
         .repeated()
         .validate(check_statements_require_semicolon)
         .delimited_by(just(LeftBrace), just(RightBrace))
@@ -420,7 +451,8 @@ where
             assertion::assertion_eq(expr_parser.clone()),
             declaration(expr_parser.clone()),
             assignment(expr_parser.clone()),
-            for_loop(expr_no_constructors, statement),
+            for_loop(expr_no_constructors, statement.clone()),
+            function::local_function(statement),
             break_statement(),
             continue_statement(),
             return_statement(expr_parser.clone()),
@@ -919,6 +951,7 @@ where
     recursive(move |term_parser| {
         choice((
             not(term_parser.clone()),
+            bit_not(term_parser.clone()),
             negation(term_parser.clone()),
             mutable_reference(term_parser.clone()),
             dereference(term_parser),
@@ -961,6 +994,7 @@ where
     enum UnaryRhs {
         Call(Vec<Expression>),
         ArrayIndex(Expression),
+        SubArray(u64, u64),
         Cast(UnresolvedType),
         MemberAccess((Ident, Option<Vec<Expression>>)),
     }
@@ -974,6 +1008,28 @@ where
         .delimited_by(just(Token::LeftBracket), just(Token::RightBracket))
         .map(UnaryRhs::ArrayIndex);
 
+    // `[start..end]` in `arr[start..end]`, where both bounds are integer literals
+    let range_bound = filter_map(|span, token: Token| {
+        let bound = match &token {
+            Token::Int(value) => value.try_to_u64(),
+            _ => None,
+        };
+        let label = ParsingRuleLabel::TokenKind(TokenKind::Literal);
+        bound.ok_or_else(|| ParserError::expected_label(label, token, span))
+    });
+    let sub_array_rhs = range_bound
+        .clone()
+        .then_ignore(just(Token::DoubleDot))
+        .then(range_bound)
+        .delimited_by(just(Token::LeftBracket), just(Token::RightBracket))
+        .validate(|(start, end), span, emit| {
+            if start > end {
+                let reason = ParserErrorReason::InvalidSubArrayRange(start, end);
+                emit(ParserError::with_reason(reason, span));
+            }
+            UnaryRhs::SubArray(start, end)
+        });
+
     // `as Type` in `atom as Type`
     let cast_rhs = keyword(Keyword::As)
         .ignore_then(parse_type())
@@ -987,7 +1043,7 @@ where
         .map(UnaryRhs::MemberAccess)
         .labelled(ParsingRuleLabel::FieldAccess);
 
-    let rhs = choice((call_rhs, array_rhs, cast_rhs, member_rhs));
+    let rhs = choice((call_rhs, sub_array_rhs, array_rhs, cast_rhs, member_rhs));
 
     foldl_with_span(
         atom(expr_parser, expr_no_constructors, statement, allow_constructors),
@@ -995,6 +1051,7 @@ where
         |lhs, rhs, span| match rhs {
             UnaryRhs::Call(args) => Expression::call(lhs, args, span),
             UnaryRhs::ArrayIndex(index) => Expression::index(lhs, index, span),
+            UnaryRhs::SubArray(start, end) => Expression::sub_array(lhs, start, end, span),
             UnaryRhs::Cast(r#type) => Expression::cast(lhs, r#type, span),
             UnaryRhs::MemberAccess(field) => {
                 Expression::member_access_or_method_call(lhs, field, span)
@@ -1235,7 +1292,7 @@ where
 mod test {
     use super::test_helpers::*;
     use super::*;
-    use crate::{ArrayLiteral, Literal};
+    use crate::{ArrayLiteral, Literal, TraitImplItem, UnaryOp};
 
     #[test]
     fn parse_infix() {
@@ -1244,6 +1301,41 @@ mod test {
         parse_all_failing(expression(), vec!["y ! x"]);
     }
 
+    #[test]
+    fn parse_logical_operators() {
+        // `||` binds more loosely than `&&`, which binds more loosely than comparisons
+        let expr = parse_with(expression(), "a == b && c == d || e").unwrap();
+        let ExpressionKind::Infix(or) = expr.kind else {
+            panic!("expected an infix expression");
+        };
+        assert_eq!(or.operator.contents, BinaryOpKind::LogicalOr);
+        let ExpressionKind::Infix(and) = or.lhs.kind else {
+            panic!("expected an infix expression");
+        };
+        assert_eq!(and.operator.contents, BinaryOpKind::LogicalAnd);
+        let ExpressionKind::Infix(equal) = and.lhs.kind else {
+            panic!("expected an infix expression");
+        };
+        assert_eq!(equal.operator.contents, BinaryOpKind::Equal);
+
+        // A closure without parameters is still a closure
+        let expr = parse_with(expression(), "|| x").unwrap();
+        assert!(matches!(expr.kind, ExpressionKind::Lambda(_)));
+        parse_all(expression(), vec!["|x| x || y", "|| -> bool { x && y }", "(x & y) && z"]);
+        parse_all_failing(expression(), vec!["x &&", "|| y ||"]);
+    }
+
+    #[test]
+    fn parse_bit_not() {
+        let expr = parse_with(expression(), "~x").unwrap();
+        match expr.kind {
+            ExpressionKind::Prefix(prefix) => assert_eq!(prefix.operator, UnaryOp::BitNot),
+            _ => unreachable!("expected a prefix expression"),
+        }
+        parse_all(expression(), vec!["~x & 0xff", "~(x + 1)", "~~x", "!~x"]);
+        parse_all_failing(expression(), vec!["x ~ y"]);
+    }
+
     #[test]
     fn parse_function_call() {
         let valid = vec![
@@ -1334,11 +1426,37 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_sub_array() {
+        let expr = parse_with(expression(), "arr[1..3]").unwrap();
+        let ExpressionKind::SubArray(sub_array) = expr.kind else {
+            panic!("Expected a sub-array expression, got {expr:?}");
+        };
+        assert_eq!((sub_array.start, sub_array.end), (1, 3));
+
+        parse_all(expression(), vec!["arr[0..0]", "foo()[2..4]", "arr[1..3][0]"]);
+        parse_all_failing(expression(), vec!["arr[3..1]", "arr[1..]", "arr[..2]", "arr[i..3]"]);
+    }
+
     #[test]
     fn parse_type_expression() {
         parse_all(type_expression(), vec!["(123)", "123", "(1 + 1)", "(1 + (1))"]);
     }
 
+    #[test]
+    fn parse_string_type() {
+        let typ = parse_with(parse_type(), "str<8>").unwrap();
+        let UnresolvedTypeData::String(Some(UnresolvedTypeExpression::Constant(length, _))) =
+            typ.typ
+        else {
+            panic!("Expected a string type of constant length, got {typ:?}");
+        };
+        assert_eq!(length, 8);
+
+        parse_all(parse_type(), vec!["str<N>", "str"]);
+        parse_all_failing(parse_type(), vec!["str<8", "str<>"]);
+    }
+
     #[test]
     fn parse_array_sugar() {
         let valid = vec!["[0;7]", "[(1, 2); 4]", "[0;Four]", "[2;1+3-a]"];
@@ -1423,6 +1541,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_local_function() {
+        let res = parse_with(
+            block(fresh_statement()),
+            "{ fn double(x: Field) -> Field { x * 2 } double(3) }",
+        )
+        .unwrap();
+        assert_eq!(res.0.len(), 2);
+        match &res.0[0].kind {
+            StatementKind::Function(function) => {
+                assert_eq!(function.name.0.contents, "double");
+                assert_eq!(function.parameters.len(), 1);
+            }
+            _ => unreachable!(),
+        }
+        assert!(matches!(unwrap_expr(&res.0[1].kind), ExpressionKind::Call(_)));
+
+        parse_all_failing(
+            block(fresh_statement()),
+            vec![
+                "{ fn double(x) -> Field { x * 2 } }", // Parameters must have a type
+                "{ fn double<T>(x: T) -> T { x } }",   // Local functions cannot be generic
+            ],
+        );
+    }
+
     /// Extract an Statement::Expression from a statement or panic
     fn unwrap_expr(stmt: &StatementKind) -> &ExpressionKind {
         match stmt {
@@ -1441,6 +1585,31 @@ mod test {
         parse_all(declaration(expression()), vec!["let _ = 42", "let x = y", "let x : u8 = y"]);
     }
 
+    #[test]
+    fn parse_compound_assignment() {
+        let cases = [
+            ("x += 1", BinaryOpKind::Add),
+            ("x -= 1", BinaryOpKind::Subtract),
+            ("x *= 1", BinaryOpKind::Multiply),
+            ("x %= 1", BinaryOpKind::Modulo),
+            ("x >>= 1", BinaryOpKind::ShiftRight),
+        ];
+
+        // Each statement is desugared into `x = x <op> 1`
+        for (src, expected_operator) in cases {
+            match parse_with(assignment(expression()), src).unwrap() {
+                StatementKind::Assign(assign) => match assign.expression.kind {
+                    ExpressionKind::Infix(infix) => {
+                        assert_eq!(infix.operator.contents, expected_operator);
+                        assert_eq!(infix.lhs.to_string(), "x");
+                    }
+                    _ => unreachable!("expected an infix expression for {src}"),
+                },
+                _ => unreachable!("expected an assignment for {src}"),
+            }
+        }
+    }
+
     #[test]
     fn parse_invalid_pub() {
         // pub cannot be used to declare a statement
@@ -1494,6 +1663,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_if_else_chain() {
+        let src = "if x { 1 } else if y { 2 } else if z { 3 } else { 4 }";
+        let mut expr =
+            parse_with(if_expr(expression_no_constructors(expression()), fresh_statement()), src)
+                .unwrap();
+
+        // Each `else if` is desugared into an `else` block wrapping the nested `if` expression.
+        for _ in 0..2 {
+            let ExpressionKind::If(if_expression) = expr else {
+                panic!("Expected an if expression");
+            };
+            let alternative = if_expression.alternative.expect("Expected an else branch");
+            let ExpressionKind::Block(block) = alternative.kind else {
+                panic!("Expected the else branch to be a block");
+            };
+            assert_eq!(block.0.len(), 1);
+            expr = unwrap_expr(&block.0[0].kind).clone();
+        }
+
+        let ExpressionKind::If(if_expression) = expr else {
+            panic!("Expected an if expression");
+        };
+        let alternative = if_expression.alternative.expect("Expected an else branch");
+        assert!(matches!(alternative.kind, ExpressionKind::Block(_)));
+    }
+
     #[test]
     fn parse_module_declaration() {
         parse_with(module_declaration(), "mod foo").unwrap();
@@ -1620,6 +1816,150 @@ mod test {
         check_cases_with_errors(&cases[..], fresh_statement());
     }
 
+    #[test]
+    fn block_reports_errors_from_multiple_statements() {
+        let src = "{ let a: = 1; let b = 2; let c: = 3; }";
+        let (block_expr, errors) = parse_recover(block(fresh_statement()), src);
+
+        // Parsing continues past the first malformed statement so both errors are reported
+        assert_eq!(errors.len(), 2, "Expected 2 errors, got: {errors:?}");
+        assert_eq!(block_expr.expect("block should be recovered").0.len(), 3);
+    }
+
+    #[test]
+    fn parse_program_reports_all_errors() {
+        // The lexer error at the end of the file must not mask the earlier parser error.
+        let src = "
+            fn main() {
+                let x: = 1;
+            }
+            fn foo() -> Field {
+                5__5
+            }
+        ";
+        let (_, errors) = parse_program(src);
+        assert_eq!(errors.len(), 2, "expected 2 errors, got: {errors:?}");
+
+        let lexer_errors = errors
+            .iter()
+            .filter(|error| matches!(error.reason(), Some(ParserErrorReason::Lexer(_))))
+            .count();
+        assert_eq!(lexer_errors, 1);
+        assert_ne!(errors[0].span(), errors[1].span());
+    }
+
+    #[test]
+    fn parse_program_rejects_pub_statement() {
+        // `pub` statements in function bodies are reported as errors rather than being
+        // passed on to later passes.
+        let src = "
+            fn main(y: Field) {
+                pub x = y;
+                assert(y == 1);
+            }
+        ";
+        let (_, errors) = parse_program(src);
+        assert!(!errors.is_empty(), "expected `pub x = y` to be rejected");
+        assert!(errors[0].span().start() >= src.find("pub x").unwrap() as u32);
+    }
+
+    #[test]
+    fn parse_program_with_doc_comments_attaches_them_to_functions() {
+        let src = "
+            /// Adds one.
+            /// Second line.
+            #[test]
+            pub fn foo() {}
+
+            // Not a doc comment
+            fn bar() {}
+
+            /// Documents the global instead of `baz`
+            global X = 1;
+            fn baz() {}
+
+            impl Foo {
+                /** Documents a method */
+                fn method(self) {}
+            }
+
+            struct Bar {
+                /// Doc comments on fields are accepted
+                x: Field,
+            }
+
+            trait Baz {
+                /// Doc comments on trait methods are accepted
+                fn baz(self);
+            }
+
+            impl Baz for Bar {
+                /// Documents a trait method
+                fn baz(self) {
+                    /// Doc comments on statements are accepted
+                    let _ = 1;
+                }
+            }
+        ";
+        let (module, errors) = parse_program_with_doc_comments(src);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+
+        let module = module.into_sorted();
+        let doc_comments: Vec<_> = module
+            .functions
+            .iter()
+            .map(|function| (function.name(), function.def().doc_comments.clone()))
+            .collect();
+        assert_eq!(
+            doc_comments,
+            vec![
+                ("foo", vec!["Adds one.".to_string(), "Second line.".to_string()]),
+                ("bar", vec![]),
+                ("baz", vec![]),
+            ]
+        );
+
+        let (method, _) = &module.impls[0].methods[0];
+        assert_eq!(method.def().doc_comments, vec!["Documents a method ".to_string()]);
+
+        let TraitImplItem::Function(method) = &module.trait_impls[0].items[0] else {
+            unreachable!("expected a trait method");
+        };
+        assert_eq!(method.def().doc_comments, vec!["Documents a trait method".to_string()]);
+
+        // Doc comments are only kept for tooling which asks for them.
+        let (module, errors) = parse_program(src);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+        let module = module.into_sorted();
+        assert!(module.functions.iter().all(|function| function.def().doc_comments.is_empty()));
+    }
+
+    #[test]
+    fn parse_program_accepts_trailing_doc_comments() {
+        let sources = [
+            // At the end of a block
+            "fn main() {
+                let _ = 1;
+                /// Documents nothing
+            }",
+            // At the end of an impl
+            "impl Foo {
+                fn method(self) {}
+                /// Documents nothing
+            }",
+            // At the end of the file
+            "fn main() {}
+            /// Documents nothing",
+            "fn main() {}
+            /** Documents nothing */",
+        ];
+
+        for src in sources {
+            let (_, errors) = parse_program(src);
+            assert!(errors.is_empty(), "unexpected errors in {src:?}: {errors:?}");
+        }
+    }
+
     #[test]
     fn return_validation() {
         let cases = [
@@ -0,0 +1,23 @@
+use chumsky::prelude::*;
+
+use crate::{
+    parser::{labels::ParsingRuleLabel, NoirParser, ParserError},
+    token::{DocStyle, Token},
+};
+
+/// Parses a single outer doc comment (`///` or `/** */`), returning its text without the
+/// space which conventionally follows the comment marker.
+fn outer_doc_comment() -> impl NoirParser<String> {
+    filter_map(|span, found: Token| match found {
+        Token::LineComment(comment, Some(DocStyle::Outer))
+        | Token::BlockComment(comment, Some(DocStyle::Outer)) => {
+            Ok(comment.strip_prefix(' ').map(ToOwned::to_owned).unwrap_or(comment))
+        }
+        _ => Err(ParserError::expected_label(ParsingRuleLabel::DocComment, found, span)),
+    })
+}
+
+/// outer_doc_comments: outer_doc_comment*
+pub(super) fn outer_doc_comments() -> impl NoirParser<Vec<String>> {
+    outer_doc_comment().repeated()
+}
@@ -1,6 +1,8 @@
 use super::{
     attributes::{attributes, validate_attributes},
-    block, fresh_statement, ident, keyword, nothing, optional_distinctness, optional_visibility,
+    block,
+    doc_comments::outer_doc_comments,
+    fresh_statement, ident, keyword, nothing, optional_distinctness, optional_visibility,
     parameter_name_recovery, parameter_recovery, parenthesized, parse_type, pattern,
     self_parameter, where_clause, NoirParser,
 };
@@ -8,16 +10,17 @@ use crate::parser::labels::ParsingRuleLabel;
 use crate::parser::spanned;
 use crate::token::{Keyword, Token};
 use crate::{
-    Distinctness, FunctionDefinition, FunctionReturnType, Ident, ItemVisibility, NoirFunction,
-    Param, Visibility,
+    Distinctness, FunctionDefinition, FunctionReturnType, Ident, ItemVisibility, LocalFunction,
+    NoirFunction, Param, Pattern, StatementKind, UnresolvedType, Visibility,
 };
 
 use chumsky::prelude::*;
 
-/// function_definition: attribute function_modifiers 'fn' ident generics '(' function_parameters ')' function_return_type block
-///                      function_modifiers 'fn' ident generics '(' function_parameters ')' function_return_type block
+/// function_definition: outer_doc_comments attribute function_modifiers 'fn' ident generics '(' function_parameters ')' function_return_type block
+///                      outer_doc_comments function_modifiers 'fn' ident generics '(' function_parameters ')' function_return_type block
 pub(super) fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunction> {
-    attributes()
+    outer_doc_comments()
+        .then(attributes())
         .then(function_modifiers())
         .then_ignore(keyword(Keyword::Fn))
         .then(ident())
@@ -27,7 +30,7 @@ pub(super) fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunct
         .then(where_clause())
         .then(spanned(block(fresh_statement())))
         .validate(|(((args, ret), where_clause), (body, body_span)), span, emit| {
-            let ((((attributes, modifiers), name), generics), parameters) = args;
+            let (((((doc_comments, attributes), modifiers), name), generics), parameters) = args;
 
             // Validate collected attributes, filtering them into function and secondary variants
             let attributes = validate_attributes(attributes, span, emit);
@@ -44,11 +47,48 @@ pub(super) fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunct
                 return_type: ret.1,
                 return_visibility: ret.0 .1,
                 return_distinctness: ret.0 .0,
+                doc_comments,
             }
             .into()
         })
 }
 
+/// local_function: 'fn' ident '(' local_function_parameters ')' local_function_return_type block
+///
+/// A function defined as a statement within a block. Unlike top-level functions these
+/// cannot have attributes, modifiers or generics.
+pub(super) fn local_function<'a>(
+    statement: impl NoirParser<StatementKind> + 'a,
+) -> impl NoirParser<StatementKind> + 'a {
+    keyword(Keyword::Fn)
+        .ignore_then(ident())
+        .then(parenthesized(local_function_parameters()))
+        .then(local_function_return_type())
+        .then(spanned(block(statement)))
+        .map(|(((name, parameters), return_type), (body, span))| {
+            StatementKind::Function(LocalFunction { name, parameters, return_type, body, span })
+        })
+}
+
+fn local_function_parameters() -> impl NoirParser<Vec<(Pattern, UnresolvedType)>> {
+    let typ = parse_type().recover_via(parameter_recovery());
+
+    let parameter =
+        pattern().recover_via(parameter_name_recovery()).then_ignore(just(Token::Colon)).then(typ);
+
+    parameter
+        .separated_by(just(Token::Comma))
+        .allow_trailing()
+        .labelled(ParsingRuleLabel::Parameter)
+}
+
+fn local_function_return_type() -> impl NoirParser<FunctionReturnType> {
+    just(Token::Arrow).ignore_then(parse_type()).or_not().map_with_span(|ret, span| match ret {
+        Some(typ) => FunctionReturnType::Ty(typ),
+        None => FunctionReturnType::Default(span),
+    })
+}
+
 /// visibility_modifier: 'pub(crate)'? 'pub'? ''
 fn visibility_modifier() -> impl NoirParser<ItemVisibility> {
     let is_pub_crate = (keyword(Keyword::Pub)
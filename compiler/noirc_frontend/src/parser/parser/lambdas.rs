@@ -11,8 +11,12 @@ use super::{parse_type, pattern};
 pub(super) fn lambda<'a>(
     expr_parser: impl NoirParser<Expression> + 'a,
 ) -> impl NoirParser<ExpressionKind> + 'a {
+    // A closure without parameters is lexed as a single `||` token
+    let no_parameters = just(Token::LogicalOr).to(Vec::new());
+
     lambda_parameters()
         .delimited_by(just(Token::Pipe), just(Token::Pipe))
+        .or(no_parameters)
         .then(lambda_return_type())
         .then(expr_parser)
         .map(|((parameters, return_type), body)| {
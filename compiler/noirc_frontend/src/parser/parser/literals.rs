@@ -11,6 +11,7 @@ use super::primitives::token_kind;
 pub(super) fn literal() -> impl NoirParser<ExpressionKind> {
     token_kind(TokenKind::Literal).map(|token| match token {
         Token::Int(x) => ExpressionKind::integer(x),
+        Token::Char(c) => ExpressionKind::character(c),
         Token::Bool(b) => ExpressionKind::boolean(b),
         Token::Str(s) => ExpressionKind::string(s),
         Token::RawStr(s, hashes) => ExpressionKind::raw_string(s, hashes),
@@ -25,7 +26,7 @@ mod test {
     use crate::parser::parser::{
         expression, expression_no_constructors, fresh_statement, term, test_helpers::*,
     };
-    use crate::Literal;
+    use crate::{BinaryOpKind, Literal};
 
     fn expr_to_lit(expr: ExpressionKind) -> Literal {
         match expr {
@@ -54,6 +55,15 @@ mod test {
         };
     }
 
+    #[test]
+    fn parse_char() {
+        let expr = parse_with(literal(), "'A'").unwrap();
+        match expr_to_lit(expr) {
+            Literal::Char(c) => assert_eq!(c, 'A'),
+            _ => unreachable!(),
+        };
+    }
+
     #[test]
     fn parse_bool() {
         let expr_true = parse_with(literal(), "true").unwrap();
@@ -80,6 +90,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_negative_integer() {
+        // Negating an integer literal is folded into the literal's sign
+        let parser = || {
+            term(expression(), expression_no_constructors(expression()), fresh_statement(), true)
+        };
+        match expr_to_lit(parse_with(parser(), "-5").unwrap().kind) {
+            Literal::Integer(value, true) => assert_eq!(value, 5_u128.into()),
+            _ => unreachable!("expected a negative integer literal"),
+        }
+        match expr_to_lit(parse_with(parser(), "--5").unwrap().kind) {
+            Literal::Integer(value, false) => assert_eq!(value, 5_u128.into()),
+            _ => unreachable!("expected a positive integer literal"),
+        }
+
+        let expr = parse_with(expression(), "x - -5").unwrap();
+        let ExpressionKind::Infix(infix) = expr.kind else {
+            unreachable!("expected an infix expression");
+        };
+        assert_eq!(infix.operator.contents, BinaryOpKind::Subtract);
+        assert!(matches!(infix.rhs.kind, ExpressionKind::Literal(Literal::Integer(_, true))));
+    }
+
     #[test]
     fn parse_raw_string_expr() {
         let cases = vec![
@@ -49,6 +49,15 @@ where
     just(Token::Bang).ignore_then(term_parser).map(|rhs| ExpressionKind::prefix(UnaryOp::Not, rhs))
 }
 
+pub(super) fn bit_not<P>(term_parser: P) -> impl NoirParser<ExpressionKind>
+where
+    P: ExprParser,
+{
+    just(Token::Tilde)
+        .ignore_then(term_parser)
+        .map(|rhs| ExpressionKind::prefix(UnaryOp::BitNot, rhs))
+}
+
 pub(super) fn negation<P>(term_parser: P) -> impl NoirParser<ExpressionKind>
 where
     P: ExprParser,
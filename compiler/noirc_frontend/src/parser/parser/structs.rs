@@ -6,6 +6,7 @@ use crate::{
     parser::{
         parser::{
             attributes::attributes,
+            doc_comments::outer_doc_comments,
             function, parse_type,
             primitives::{ident, keyword},
         },
@@ -41,7 +42,8 @@ pub(super) fn struct_definition() -> impl NoirParser<TopLevelStatement> {
 }
 
 fn struct_fields() -> impl NoirParser<Vec<(Ident, UnresolvedType)>> {
-    ident()
+    outer_doc_comments()
+        .ignore_then(ident())
         .then_ignore(just(Token::Colon))
         .then(parse_type())
         .separated_by(just(Token::Comma))
@@ -1,8 +1,8 @@
 use chumsky::prelude::*;
 
 use super::{
-    block, expression, fresh_statement, function, function_declaration_parameters,
-    function_return_type,
+    block, doc_comments::outer_doc_comments, expression, fresh_statement, function,
+    function_declaration_parameters, function_return_type,
 };
 
 use crate::{
@@ -31,10 +31,10 @@ pub(super) fn trait_definition() -> impl NoirParser<TopLevelStatement> {
 }
 
 fn trait_body() -> impl NoirParser<Vec<TraitItem>> {
-    trait_function_declaration()
-        .or(trait_type_declaration())
-        .or(trait_constant_declaration())
-        .repeated()
+    let item =
+        trait_function_declaration().or(trait_type_declaration()).or(trait_constant_declaration());
+
+    outer_doc_comments().ignore_then(item).repeated()
 }
 
 fn optional_default_value() -> impl NoirParser<Option<Expression>> {
@@ -128,7 +128,8 @@ fn trait_implementation_body() -> impl NoirParser<Vec<TraitImplItem>> {
         TraitImplItem::Function(f)
     });
 
-    let alias = keyword(Keyword::Type)
+    let alias = outer_doc_comments()
+        .ignore_then(keyword(Keyword::Type))
         .ignore_then(ident())
         .then_ignore(just(Token::Assign))
         .then(parse_type())
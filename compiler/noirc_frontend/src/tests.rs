@@ -10,7 +10,7 @@ mod test {
     use fm::FileId;
 
     use iter_extended::vecmap;
-    use noirc_errors::Location;
+    use noirc_errors::{CustomDiagnostic, Location, Span};
 
     use crate::hir::def_collector::dc_crate::CompilationError;
     use crate::hir::def_collector::errors::{DefCollectorErrorKind, DuplicateType};
@@ -18,12 +18,13 @@ mod test {
     use crate::hir::resolution::errors::ResolverError;
     use crate::hir::resolution::import::PathResolutionError;
     use crate::hir::type_check::TypeCheckError;
-    use crate::hir::Context;
+    use crate::hir::{Context, FunctionNameMatch};
     use crate::node_interner::{NodeInterner, StmtId};
 
     use crate::hir::def_collector::dc_crate::DefCollector;
     use crate::hir_def::expr::HirExpression;
     use crate::hir_def::stmt::HirStatement;
+    use crate::hir_def::types::Type;
     use crate::monomorphization::monomorphize;
     use crate::parser::ParserErrorReason;
     use crate::ParsedModule;
@@ -674,6 +675,32 @@ mod test {
         }
     }
 
+    #[test]
+    fn duplicate_function_definitions_in_module() {
+        let src = "
+        fn foo() -> Field { 1 }
+        fn foo() -> Field { 2 }
+        fn main() {}
+        ";
+        let errors = get_program_errors(src);
+        assert!(!has_parser_error(&errors));
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+
+        let second_foo_start = src.rfind("foo").unwrap() as u32;
+        match &errors[0].0 {
+            CompilationError::DefinitionError(DefCollectorErrorKind::Duplicate {
+                typ,
+                first_def,
+                second_def,
+            }) => {
+                assert_eq!(typ, &DuplicateType::Function);
+                assert_eq!(first_def, "foo");
+                assert_eq!(second_def.span(), Span::from(second_foo_start..second_foo_start + 3));
+            }
+            err => panic!("Expected a duplicate function error, found {:?}", err),
+        }
+    }
+
     #[test]
     fn check_trait_duplicate_implementation() {
         let src = "
@@ -847,6 +874,271 @@ mod test {
         }
     }
 
+    #[test]
+    fn resolve_unused_parameter() {
+        let src = r#"
+            fn main(x: Field, y: Field, _z: Field) {
+                assert(y == 1);
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        // `_z` is exempt from the check thanks to its underscore prefix
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::UnusedVariable { ident }) => {
+                assert_eq!(&ident.0.contents, "x");
+            }
+            _ => unreachable!("we should only have an unused var error"),
+        }
+    }
+
+    #[test]
+    fn resolve_duplicate_generic() {
+        let src = r#"
+            fn id<T, T>(x: T) -> T {
+                x
+            }
+
+            fn main(x: Field) {
+                assert(id(x) == x);
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::DuplicateDefinition {
+                name, ..
+            }) => {
+                assert_eq!(name, "T");
+            }
+            _ => unreachable!("we should only have a duplicate definition error"),
+        }
+    }
+
+    #[test]
+    fn duplicate_definition_reports_both_spans() {
+        let src = "fn f(x: u8, x: u8) -> u8 { x } fn main(y: u8) { assert(f(y, y) == y); }";
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        let error = match &errors[0].0 {
+            CompilationError::ResolverError(error) => error.clone(),
+            _ => unreachable!("we should only have a resolver error"),
+        };
+        let (first_span, second_span) = match &error {
+            ResolverError::DuplicateDefinition { first_span, second_span, .. } => {
+                (*first_span, *second_span)
+            }
+            _ => unreachable!("we should only have a duplicate definition error"),
+        };
+        // Both the original declaration of `x` and the conflicting one are reported
+        assert_eq!(first_span, Span::from(5..6));
+        assert_eq!(second_span, Span::from(12..13));
+
+        let diagnostic = CustomDiagnostic::from(error);
+        let label_spans = vecmap(&diagnostic.secondaries, |label| label.span);
+        assert_eq!(label_spans, vec![first_span, second_span]);
+    }
+
+    #[test]
+    fn ignored_parameters_may_repeat() {
+        let src = r#"
+            fn f(_: u8, _: u8, x: u8) -> u8 { x }
+            fn main(y: u8) {
+                let g = |_: u8, _: u8| y;
+                assert(f(y, y, y) == g(y, y));
+            }
+        "#;
+        assert_eq!(get_program_errors(src).len(), 0);
+    }
+
+    #[test]
+    fn cast_to_non_numeric_type() {
+        let src = r#"
+            fn main(x: Field) {
+                let _ = x as [Field; 2];
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            &errors[0].0,
+            CompilationError::TypeError(TypeCheckError::UnsupportedCast { .. })
+        ));
+    }
+
+    #[test]
+    fn call_non_function_variable() {
+        let src = r#"
+            fn main(x: Field) {
+                let _ = x(1);
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::ExpectedFunction { found, .. }) => {
+                assert_eq!(found, &Type::FieldElement);
+            }
+            _ => unreachable!("we should only have an expected function error"),
+        }
+    }
+
+    #[test]
+    fn bit_not_on_bool() {
+        let src = r#"
+            fn main(x: bool) {
+                let _ = ~x;
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::InvalidUnaryOp { kind, .. }) => {
+                assert_eq!(kind, "bool");
+            }
+            _ => unreachable!("we should only have an invalid unary op error"),
+        }
+    }
+
+    #[test]
+    fn bit_not_on_integer_literal() {
+        // An unsuffixed literal is inferred to be an integer rather than rejected
+        let src = r#"
+            fn main(y: u8) {
+                let x: u8 = ~0;
+                assert(x == 255);
+                assert(~1 & y == 0);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn logical_operators_require_booleans() {
+        let src = r#"
+            fn main(x: bool, y: u8) {
+                let _a: bool = x && (y == 1);
+                let _b = x || y;
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::TypeMismatch {
+                expected_typ,
+                expr_typ,
+                ..
+            }) => {
+                assert_eq!(expected_typ, "bool");
+                assert_eq!(expr_typ, "u8");
+            }
+            _ => unreachable!("we should only have a type mismatch error"),
+        }
+    }
+
+    #[test]
+    fn assert_eq_rejects_arrays_of_different_shapes() {
+        let src = r#"
+            fn main(x: [[Field; 2]; 2], y: [[Field; 2]; 3]) {
+                assert_eq(x, y);
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::TypeMismatchWithSource {
+                expected,
+                actual,
+                ..
+            }) => {
+                assert_eq!(expected.to_string(), "[[Field; 2]; 2]");
+                assert_eq!(actual.to_string(), "[[Field; 2]; 3]");
+            }
+            _ => unreachable!("we should only have an array length mismatch error"),
+        }
+    }
+
+    #[test]
+    fn block_value_is_its_trailing_expression() {
+        let src = r#"
+            fn main(x: Field) {
+                let _a: Field = { x };
+                let _b: () = { x; };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+
+        let src = r#"
+            fn main(x: Field) {
+                let _c: Field = { x; };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::TypeError(TypeCheckError::TypeMismatch {
+                expected_typ,
+                expr_typ,
+                ..
+            }) => {
+                assert_eq!(expected_typ, "Field");
+                assert_eq!(expr_typ, "()");
+            }
+            _ => unreachable!("we should only have a type mismatch error"),
+        }
+    }
+
+    #[test]
+    fn resolve_local_function() {
+        let src = r#"
+            fn main(x: Field) {
+                fn double(y: Field) -> Field {
+                    y * 2
+                }
+                assert(double(x) == x + x);
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn resolve_local_function_out_of_scope() {
+        let src = r#"
+            fn main(x: Field) {
+                {
+                    fn double(y: Field) -> Field {
+                        y * 2
+                    }
+                    assert(double(x) == x + x);
+                }
+                assert(double(x) == x + x);
+            }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        // `double` is only in scope within the block it was defined in
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::VariableNotDeclared {
+                name,
+                span: _,
+            }) => assert_eq!(name, "double"),
+            _ => unreachable!("we should only have an undeclared variable error"),
+        }
+    }
+
     #[test]
     fn resolve_unresolved_var() {
         let src = r#"
@@ -893,6 +1185,35 @@ mod test {
         }
     }
 
+    #[test]
+    fn resolve_aliased_import() {
+        // Only the alias is brought into scope, not the original name
+        let src = r#"
+            mod foo {
+                pub fn bar(x: Field) -> Field {
+                    x + 1
+                }
+            }
+
+            use foo::bar as h;
+
+            fn main(x : Field) {
+                assert(h(x) == x + 1);
+                assert(bar(x) == x + 1);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::VariableNotDeclared {
+                name, ..
+            }) => {
+                assert_eq!(name, "bar");
+            }
+            _ => unimplemented!("we should only have an undeclared `bar`"),
+        }
+    }
+
     #[test]
     fn resolve_literal_expr() {
         let src = r#"
@@ -965,6 +1286,64 @@ mod test {
         assert!(get_program_errors(src).is_empty());
     }
 
+    #[test]
+    fn resolve_forward_references() {
+        let src = r#"
+            fn main(x : Field) {
+                let foo = Foo { x };
+                assert(foo.double() == helper(x));
+                assert(inner::later(x) == x);
+            }
+
+            struct Foo {
+                x: Field,
+            }
+
+            impl Foo {
+                fn double(self) -> Field {
+                    self.add(self.x)
+                }
+
+                fn add(self, y: Field) -> Field {
+                    self.x + y
+                }
+            }
+
+            fn helper(x : Field) -> Field {
+                x * 2
+            }
+
+            mod inner {
+                pub fn later(x : Field) -> Field {
+                    x
+                }
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+    }
+
+    #[test]
+    fn resolve_reversed_for_range() {
+        let src = r#"
+            fn main(x : u64) {
+                for i in 5..2 {
+                    let _z = x + i;
+                };
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1);
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::ReversedRange {
+                start, end, ..
+            }) => {
+                assert_eq!((*start, *end), (5, 2));
+            }
+            other => panic!("expected a reversed range error, got {other:?}"),
+        }
+    }
+
     #[test]
     fn resolve_call_expr() {
         let src = r#"
@@ -996,6 +1375,71 @@ mod test {
         assert!(get_program_errors(src).is_empty());
     }
 
+    #[test]
+    fn resolve_block_expression_scope() {
+        let src = r#"
+            fn main(x : Field) {
+                let y = {
+                    let t = x * x;
+                    t + 1
+                };
+                assert(y == t);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        // `t` is only in scope within the block which defines it
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::VariableNotDeclared {
+                name,
+                span: _,
+            }) => assert_eq!(name, "t"),
+            _ => unimplemented!("we should only have an unresolved variable"),
+        }
+    }
+
+    #[test]
+    fn resolve_shadowing_in_nested_block() {
+        // The inner `x` has a different type, so resolving either use of `x` to the wrong
+        // definition would be reported by the type checker.
+        let src = r#"
+            fn main(y : Field) {
+                let x = y;
+                {
+                    let x = y == 0;
+                    assert(x);
+                }
+                assert(x + 1 != y);
+            }
+        "#;
+        assert!(get_program_errors(src).is_empty());
+    }
+
+    #[test]
+    fn resolve_for_loop_scope() {
+        let src = r#"
+            fn main(x : u64) {
+                for i in 0..10 {
+                    let z = x + i;
+                    assert(z != 0);
+                }
+                assert(z == i);
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 2, "Expected 2 errors, got: {:?}", errors);
+        // Neither the loop index nor variables declared in the loop body are visible after the loop
+        for (compilation_error, _file_id) in errors {
+            match compilation_error {
+                CompilationError::ResolverError(ResolverError::VariableNotDeclared {
+                    name,
+                    ..
+                }) => assert!(name == "z" || name == "i", "Unexpected undeclared variable {name}"),
+                _ => unimplemented!("we should only have unresolved variables"),
+            }
+        }
+    }
+
     #[test]
     fn resolve_basic_closure() {
         let src = r#"
@@ -1216,6 +1660,21 @@ fn lambda$f1(mut env$l1: (Field)) -> Field {
         assert_eq!(get_program_errors(src).len(), 0);
     }
 
+    #[test]
+    fn slice_return_type_in_entry_point() {
+        let src = r#"
+            fn main(x: Field) -> pub [Field] {
+                &[x]
+            }
+        "#;
+        let errors = get_program_errors(src);
+        assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
+        assert!(matches!(
+            &errors[0].0,
+            CompilationError::TypeError(TypeCheckError::InvalidTypeForEntryPoint { .. })
+        ));
+    }
+
     #[test]
     fn operators_in_global_used_in_type() {
         let src = r#"
@@ -1228,6 +1687,51 @@ fn lambda$f1(mut env$l1: (Field)) -> Field {
         assert_eq!(get_program_errors(src).len(), 0);
     }
 
+    fn has_invalid_array_length_error(errors: &[(CompilationError, FileId)]) -> bool {
+        errors.iter().any(|(error, _)| {
+            matches!(
+                error,
+                CompilationError::ResolverError(ResolverError::InvalidArrayLengthExpr { .. })
+            )
+        })
+    }
+
+    #[test]
+    fn len_method_on_struct_global_is_not_an_array_length() {
+        let src = r#"
+            struct Table {}
+            impl Table {
+                fn len(self) -> u64 { 3 }
+            }
+            global TABLE = Table {};
+            global LEN = TABLE.len();
+            fn main() {
+                let _array: [Field; LEN] = [1, 2, 3];
+            }
+        "#;
+        assert!(has_invalid_array_length_error(&get_program_errors(src)));
+    }
+
+    #[test]
+    fn user_defined_len_on_array_global_is_not_folded() {
+        // Only the `array_len` builtin from the standard library is folded, which is not
+        // available in these tests.
+        let src = r#"
+            trait Length {
+                fn len(self) -> u64;
+            }
+            impl Length for [Field; 3] {
+                fn len(self) -> u64 { 7 }
+            }
+            global TABLE = [2, 4, 8];
+            global LEN = TABLE.len();
+            fn main() {
+                let _array: [Field; LEN] = [1, 2, 3];
+            }
+        "#;
+        assert!(has_invalid_array_length_error(&get_program_errors(src)));
+    }
+
     #[test]
     fn break_and_continue_in_constrained_fn() {
         let src = r#"
@@ -1281,4 +1785,44 @@ fn lambda$f1(mut env$l1: (Field)) -> Field {
         "#;
         assert_eq!(get_program_errors(src).len(), 0);
     }
+
+    #[test]
+    fn discovers_test_functions() {
+        let src = r#"
+            fn main() {}
+
+            #[test]
+            fn test_root() {}
+
+            #[test(should_fail)]
+            fn test_should_fail() {
+                assert(false);
+            }
+
+            mod foo {
+                #[test]
+                fn test_nested() {}
+
+                fn not_a_test() {}
+            }
+        "#;
+        let (_, context, errors) = get_program(src);
+        assert_eq!(errors.len(), 0, "Expected no errors, got: {:?}", errors);
+
+        let crate_id = *context.def_maps.keys().next().unwrap();
+        let mut test_functions = vecmap(
+            context
+                .get_all_test_functions_in_crate_matching(&crate_id, FunctionNameMatch::Anything),
+            |(name, test_function)| (name, test_function.should_fail()),
+        );
+        test_functions.sort();
+        assert_eq!(
+            test_functions,
+            vec![
+                ("foo::test_nested".to_string(), false),
+                ("test_root".to_string(), false),
+                ("test_should_fail".to_string(), true),
+            ]
+        );
+    }
 }
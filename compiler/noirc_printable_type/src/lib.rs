@@ -79,6 +79,9 @@ pub enum ForeignCallError {
 
     #[error("Assert message resolved after an unsatisified constrain. {0}")]
     ResolvedAssertMessage(String),
+
+    #[error("Cannot register a handler for the builtin oracle {0}")]
+    BuiltinOracleOverride(String),
 }
 
 impl TryFrom<&[ForeignCallParam]> for PrintableValueDisplay {
@@ -52,12 +52,21 @@ pub enum BackendError {
 
     #[error("The backend encountered an error: {0:?}")]
     CommandFailed(String),
+
+    #[error("The program expects {expected} public inputs but {actual} were provided")]
+    PublicInputCountMismatch { expected: usize, actual: usize },
 }
 
 #[derive(Debug)]
 pub struct Backend {
     name: String,
     binary_path: PathBuf,
+
+    /// Directory in which the backend caches its CRS.
+    ///
+    /// This can be set using `NARGO_CRS_PATH` so that multiple backends can share a single CRS
+    /// rather than each downloading their own copy. Defaults to a `crs` directory next to the backend binary.
+    crs_directory: Option<PathBuf>,
 }
 
 impl Backend {
@@ -69,7 +78,14 @@ impl Backend {
 
             backends_directory().join(&name).join(BINARY_NAME)
         };
-        Backend { name, binary_path }
+        let crs_directory = std::env::var_os("NARGO_CRS_PATH").map(PathBuf::from);
+        Backend { name, binary_path, crs_directory }
+    }
+
+    /// Caches the backend's CRS in `crs_directory`, overriding `NARGO_CRS_PATH`.
+    pub fn with_crs_directory(mut self, crs_directory: PathBuf) -> Backend {
+        self.crs_directory = Some(crs_directory);
+        self
     }
 
     pub fn name(&self) -> &str {
@@ -104,7 +120,10 @@ impl Backend {
     }
 
     fn crs_directory(&self) -> PathBuf {
-        self.backend_directory().join("crs")
+        match &self.crs_directory {
+            Some(crs_directory) => crs_directory.clone(),
+            None => self.backend_directory().join("crs"),
+        }
     }
 
     fn assert_correct_version(&self) -> Result<&PathBuf, BackendError> {
@@ -147,4 +166,19 @@ mod backend {
 
         assert!(matches!(binary_path, Err(BackendError::MissingBinary)));
     }
+
+    #[test]
+    fn backends_share_crs_directory() {
+        let backend = Backend::new("backend".to_string());
+        assert_eq!(backend.crs_directory(), backend.backend_directory().join("crs"));
+
+        let crs_directory = std::env::temp_dir().join("shared_crs");
+        let first_backend =
+            Backend::new("first_backend".to_string()).with_crs_directory(crs_directory.clone());
+        let second_backend =
+            Backend::new("second_backend".to_string()).with_crs_directory(crs_directory.clone());
+
+        assert_eq!(first_backend.crs_directory(), crs_directory);
+        assert_eq!(second_backend.crs_directory(), crs_directory);
+    }
 }
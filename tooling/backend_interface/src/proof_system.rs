@@ -96,6 +96,15 @@ impl Backend {
         public_inputs: WitnessMap,
         program: &Program,
     ) -> Result<bool, BackendError> {
+        // Check this up front as the backend gives an unhelpful error for the wrong number of
+        // public inputs.
+        // TODO(https://github.com/noir-lang/noir/issues/4428)
+        let expected = program.functions[0].public_inputs().0.len();
+        let actual = public_inputs.clone().into_iter().count();
+        if expected != actual {
+            return Err(BackendError::PublicInputCountMismatch { expected, actual });
+        }
+
         let binary_path = self.assert_binary_exists()?;
         self.assert_correct_version()?;
 
@@ -186,3 +195,48 @@ pub(super) fn write_to_file(bytes: &[u8], path: &Path) -> String {
         Ok(_) => display.to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use acvm::{
+        acir::{
+            circuit::{Circuit, ExpressionWidth, Opcode, Program, PublicInputs},
+            native_types::{Expression, Witness, WitnessMap},
+        },
+        FieldElement,
+    };
+
+    use crate::{get_mock_backend, BackendError};
+
+    #[test]
+    fn verify_rejects_wrong_number_of_public_inputs() -> Result<(), BackendError> {
+        let expression = &(Witness(1) + Witness(2)) - &Expression::from(Witness(3));
+        let constraint = Opcode::AssertZero(expression);
+
+        let circuit = Circuit {
+            current_witness_index: 4,
+            expression_width: ExpressionWidth::Bounded { width: 3 },
+            opcodes: vec![constraint],
+            private_parameters: BTreeSet::from([Witness(1), Witness(2)]),
+            public_parameters: PublicInputs::default(),
+            return_values: PublicInputs(BTreeSet::from([Witness(3)])),
+            assert_messages: Default::default(),
+            recursive: false,
+        };
+        let program = Program { functions: vec![circuit] };
+
+        let mut public_inputs = WitnessMap::new();
+        public_inputs.insert(Witness(1), FieldElement::one());
+        public_inputs.insert(Witness(3), FieldElement::from(2_u128));
+
+        let result = get_mock_backend()?.verify(&[], public_inputs, &program);
+        assert!(matches!(
+            result,
+            Err(BackendError::PublicInputCountMismatch { expected: 1, actual: 2 })
+        ));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
 #![warn(unused_crate_dependencies, unused_extern_crates)]
 #![warn(unreachable_pub)]
 
-use acvm::{acir::native_types::WitnessMap, FieldElement};
+use acvm::{
+    acir::{
+        circuit::{Circuit, Opcode},
+        native_types::{Expression, Witness, WitnessMap},
+    },
+    FieldElement,
+};
 
 pub const ACVM_BACKEND_BARRETENBERG: &str = "acvm-backend-barretenberg";
 pub const BB_DOWNLOAD_URL: &str = env!("BB_BINARY_URL");
@@ -24,3 +30,214 @@ pub fn prepend_public_inputs(proof: Vec<u8>, public_inputs: WitnessMap) -> Vec<u
 
     public_inputs_bytes.chain(proof).collect()
 }
+
+/// The number of wires in each of the arithmetic gates Barretenberg creates from an
+/// [`Opcode::AssertZero`], which is why its expression width is reported as 3.
+pub const GATE_WIDTH: usize = 3;
+
+/// Returns a lower bound on the number of gates Barretenberg will use to represent `circuit`.
+///
+/// This does not require a backend so is cheap enough to give quick feedback while iterating on
+/// a program, as done by `nargo info --estimate`. Only arithmetic gates are counted exactly, so
+/// use the backend's `gates` command when the size of the whole circuit is needed.
+///
+/// `circuit` is expected to have been transformed for an expression width of [`GATE_WIDTH`].
+pub fn circuit_size_lower_bound(circuit: &Circuit) -> usize {
+    circuit.opcodes.iter().map(opcode_size_lower_bound).sum()
+}
+
+fn opcode_size_lower_bound(opcode: &Opcode) -> usize {
+    match opcode {
+        Opcode::AssertZero(expression) if to_standard_gate(expression).is_some() => 1,
+        Opcode::AssertZero(expression) => {
+            // Wide expressions are split into a chain of gates. The first and last gates hold
+            // `GATE_WIDTH - 1` terms alongside the intermediate wire linking them, while every
+            // other gate holds `GATE_WIDTH - 2` terms. Each gate holds a single multiplication.
+            let num_terms = expression.mul_terms.len() + expression.linear_combinations.len();
+            let gates_for_terms = (num_terms.saturating_sub(2) + GATE_WIDTH - 3) / (GATE_WIDTH - 2);
+            gates_for_terms.max(expression.mul_terms.len()).max(1)
+        }
+        // The size of Barretenberg's gadgets for black box functions and memory depends on the
+        // version of the backend, so we only rely on each of them needing at least one gate.
+        Opcode::BlackBoxFuncCall(_) | Opcode::MemoryOp { .. } | Opcode::MemoryInit { .. } => 1,
+        // Unconstrained execution does not add any gates and calls are proven separately.
+        Opcode::Directive(_) | Opcode::Brillig(_) | Opcode::Call { .. } => 0,
+    }
+}
+
+/// An arithmetic gate in Barretenberg's standard 3-wire format, constraining
+/// `q_m * a * b + q_l * a + q_r * b + q_o * c + q_c = 0`.
+///
+/// Wires which aren't used by the gate are `None` and have a zero selector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StandardGate {
+    pub a: Option<Witness>,
+    pub b: Option<Witness>,
+    pub c: Option<Witness>,
+    pub q_m: FieldElement,
+    pub q_l: FieldElement,
+    pub q_r: FieldElement,
+    pub q_o: FieldElement,
+    pub q_c: FieldElement,
+}
+
+/// Converts `expression` into a single [`StandardGate`].
+///
+/// Returns `None` if `expression` has more than one multiplication term or touches more than
+/// three witnesses, as it can't be represented by one gate without dropping terms.
+pub fn to_standard_gate(expression: &Expression) -> Option<StandardGate> {
+    // The witnesses assigned to wires `a`, `b` and `c` in order.
+    let mut wires: Vec<Witness> = Vec::with_capacity(GATE_WIDTH);
+    let mut linear_selectors = [FieldElement::zero(); GATE_WIDTH];
+
+    let q_m = match expression.mul_terms.as_slice() {
+        [] => FieldElement::zero(),
+        [(q_m, lhs, rhs)] => {
+            // The multiplication term must be placed on wires `a` and `b`, even for squares.
+            wires.extend([*lhs, *rhs]);
+            *q_m
+        }
+        _ => return None,
+    };
+
+    for (coefficient, witness) in &expression.linear_combinations {
+        let wire = match wires.iter().position(|wire| wire == witness) {
+            Some(wire) => wire,
+            None if wires.len() < GATE_WIDTH => {
+                wires.push(*witness);
+                wires.len() - 1
+            }
+            None => return None,
+        };
+        linear_selectors[wire] += *coefficient;
+    }
+
+    let wire = |index: usize| wires.get(index).copied();
+    let [q_l, q_r, q_o] = linear_selectors;
+    Some(StandardGate {
+        a: wire(0),
+        b: wire(1),
+        c: wire(2),
+        q_m,
+        q_l,
+        q_r,
+        q_o,
+        q_c: expression.q_c,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::{
+        acir::{
+            circuit::{
+                opcodes::{BlackBoxFuncCall, FunctionInput},
+                Circuit, Opcode,
+            },
+            native_types::{Expression, Witness},
+        },
+        FieldElement,
+    };
+
+    use super::{circuit_size_lower_bound, to_standard_gate, StandardGate};
+
+    #[test]
+    fn counts_at_least_one_gate_per_constraint() {
+        // w1 * w2 - w3 = 0
+        let multiplication = Expression {
+            mul_terms: vec![(FieldElement::one(), Witness(1), Witness(2))],
+            linear_combinations: vec![(-FieldElement::one(), Witness(3))],
+            q_c: FieldElement::zero(),
+        };
+        let range = |num_bits| {
+            Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+                input: FunctionInput { witness: Witness(3), num_bits },
+            })
+        };
+
+        let circuit = Circuit {
+            current_witness_index: 3,
+            opcodes: vec![Opcode::AssertZero(multiplication)],
+            ..Circuit::default()
+        };
+        assert_eq!(circuit_size_lower_bound(&circuit), 1);
+
+        let circuit =
+            Circuit { opcodes: vec![circuit.opcodes[0].clone(), range(8), range(32)], ..circuit };
+        // Range constraints are only counted as the single gate they need at minimum, whatever
+        // their bit size.
+        assert_eq!(circuit_size_lower_bound(&circuit), 1 + 1 + 1);
+
+        // w1 + w2 + w3 + w4 + w5 = 0 is split into a chain of three gates.
+        let sum = Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: (1..=5).map(|i| (FieldElement::one(), Witness(i))).collect(),
+            q_c: FieldElement::zero(),
+        };
+        let circuit = Circuit { opcodes: vec![Opcode::AssertZero(sum)], ..circuit };
+        assert_eq!(circuit_size_lower_bound(&circuit), 3);
+    }
+
+    #[test]
+    fn converts_expression_to_standard_gate() {
+        // 2 * w1 * w2 + 3 * w2 - w5 + 7 = 0
+        let expression = Expression {
+            mul_terms: vec![(FieldElement::from(2_u128), Witness(1), Witness(2))],
+            linear_combinations: vec![
+                (FieldElement::from(3_u128), Witness(2)),
+                (-FieldElement::one(), Witness(5)),
+            ],
+            q_c: FieldElement::from(7_u128),
+        };
+
+        assert_eq!(
+            to_standard_gate(&expression),
+            Some(StandardGate {
+                a: Some(Witness(1)),
+                b: Some(Witness(2)),
+                c: Some(Witness(5)),
+                q_m: FieldElement::from(2_u128),
+                q_l: FieldElement::zero(),
+                q_r: FieldElement::from(3_u128),
+                q_o: -FieldElement::one(),
+                q_c: FieldElement::from(7_u128),
+            })
+        );
+    }
+
+    #[test]
+    fn leaves_unused_wires_empty() {
+        // w3 - 5 = 0
+        let expression = Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: vec![(FieldElement::one(), Witness(3))],
+            q_c: -FieldElement::from(5_u128),
+        };
+
+        let gate = to_standard_gate(&expression).expect("expression fits in a single gate");
+        assert_eq!((gate.a, gate.b, gate.c), (Some(Witness(3)), None, None));
+        assert_eq!(gate.q_l, FieldElement::one());
+        assert_eq!(gate.q_r, FieldElement::zero());
+        assert_eq!(gate.q_o, FieldElement::zero());
+    }
+
+    #[test]
+    fn rejects_expressions_wider_than_a_standard_gate() {
+        let four_terms = Expression {
+            mul_terms: Vec::new(),
+            linear_combinations: (1..=4).map(|i| (FieldElement::one(), Witness(i))).collect(),
+            q_c: FieldElement::zero(),
+        };
+        assert_eq!(to_standard_gate(&four_terms), None);
+
+        let two_products = Expression {
+            mul_terms: vec![
+                (FieldElement::one(), Witness(1), Witness(2)),
+                (FieldElement::one(), Witness(1), Witness(3)),
+            ],
+            linear_combinations: Vec::new(),
+            q_c: FieldElement::zero(),
+        };
+        assert_eq!(to_standard_gate(&two_products), None);
+    }
+}
@@ -349,3 +349,36 @@ fn prepare_package_from_source_string() {
     let main_func_id = context.get_main_function(&crate_id);
     assert!(main_func_id.is_some());
 }
+
+#[test]
+fn parse_diff_reuses_cached_parses_of_unchanged_files() {
+    let client = ClientSocket::new_closed();
+    let mut state = LspState::new(&client, acvm::blackbox_solver::StubbedBlackBoxSolver);
+
+    let file_name = Path::new("main.nr");
+    let mut file_manager = file_manager_with_stdlib(Path::new(""));
+    let file_id = file_manager.add_file_with_source(file_name, "fn main() {}".to_string()).unwrap();
+
+    let parsed_files = parse_diff(&file_manager, &mut state);
+    assert_eq!(parsed_files[&file_id].0.items.len(), 1);
+
+    // Replace the cached parse with a sentinel while keeping the content hash: if the file is
+    // not re-parsed we should get the sentinel back.
+    let (_, cached_parse) = state
+        .cached_parsed_files
+        .get_mut(&file_manager.path(file_id).unwrap().to_path_buf())
+        .expect("main.nr should have been cached");
+    *cached_parse = (ParsedModule::default(), Vec::new());
+
+    let parsed_files = parse_diff(&file_manager, &mut state);
+    assert!(parsed_files[&file_id].0.items.is_empty());
+
+    // Changing the file contents invalidates the cache entry.
+    let mut file_manager = file_manager_with_stdlib(Path::new(""));
+    let file_id = file_manager
+        .add_file_with_source(file_name, "fn main() {}\nfn foo() {}".to_string())
+        .unwrap();
+
+    let parsed_files = parse_diff(&file_manager, &mut state);
+    assert_eq!(parsed_files[&file_id].0.items.len(), 2);
+}
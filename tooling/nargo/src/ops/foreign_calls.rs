@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use acvm::{
     acir::brillig::{ForeignCallParam, ForeignCallResult, Value},
     pwg::ForeignCallWaitInfo,
@@ -145,18 +147,36 @@ impl MockedCall {
     }
 }
 
-#[derive(Debug, Default)]
+/// A function which resolves calls to a custom oracle, see [`DefaultForeignCallExecutor::register_oracle`].
+pub type OracleHandler =
+    Box<dyn FnMut(&[ForeignCallParam]) -> Result<ForeignCallResult, ForeignCallError>>;
+
+#[derive(Default)]
 pub struct DefaultForeignCallExecutor {
     /// Mocks have unique ids used to identify them in Noir, allowing to update or remove them.
     last_mock_id: usize,
     /// The registered mocks
     mocked_responses: Vec<MockedCall>,
+    /// Handlers for custom oracles, keyed by oracle name
+    oracle_handlers: HashMap<String, OracleHandler>,
     /// Whether to print [`ForeignCall::Print`] output.
     show_output: bool,
     /// JSON RPC client to resolve foreign calls
     external_resolver: Option<Client>,
 }
 
+impl std::fmt::Debug for DefaultForeignCallExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefaultForeignCallExecutor")
+            .field("last_mock_id", &self.last_mock_id)
+            .field("mocked_responses", &self.mocked_responses)
+            .field("oracle_handlers", &self.oracle_handlers.keys().collect::<Vec<_>>())
+            .field("show_output", &self.show_output)
+            .field("external_resolver", &self.external_resolver)
+            .finish()
+    }
+}
+
 impl DefaultForeignCallExecutor {
     pub fn new(show_output: bool, resolver_url: Option<&str>) -> Self {
         let oracle_resolver = resolver_url.map(|resolver_url| {
@@ -170,6 +190,27 @@ impl DefaultForeignCallExecutor {
             ..DefaultForeignCallExecutor::default()
         }
     }
+
+    /// Registers `handler` to resolve all calls to the oracle `name`.
+    ///
+    /// This allows consumers to provide their own unconstrained primitives without modifying Nargo.
+    /// Mocked responses take precedence over registered handlers, which in turn take precedence
+    /// over the external resolver.
+    ///
+    /// Returns an error if `name` is one of Nargo's builtin oracles, such as `print`.
+    pub fn register_oracle(
+        &mut self,
+        name: impl Into<String>,
+        handler: impl FnMut(&[ForeignCallParam]) -> Result<ForeignCallResult, ForeignCallError>
+            + 'static,
+    ) -> Result<(), ForeignCallError> {
+        let name = name.into();
+        if ForeignCall::lookup(&name).is_some() {
+            return Err(ForeignCallError::BuiltinOracleOverride(name));
+        }
+        self.oracle_handlers.insert(name, Box::new(handler));
+        Ok(())
+    }
 }
 
 impl DefaultForeignCallExecutor {
@@ -285,8 +326,10 @@ impl ForeignCallExecutor for DefaultForeignCallExecutor {
                     .iter()
                     .position(|response| response.matches(foreign_call_name, &foreign_call.inputs));
 
-                match (mock_response_position, &self.external_resolver) {
-                    (Some(response_position), _) => {
+                let oracle_handler = self.oracle_handlers.get_mut(foreign_call_name);
+
+                match (mock_response_position, oracle_handler, &self.external_resolver) {
+                    (Some(response_position), _, _) => {
                         let mock = self
                             .mocked_responses
                             .get_mut(response_position)
@@ -302,7 +345,10 @@ impl ForeignCallExecutor for DefaultForeignCallExecutor {
 
                         Ok(result.into())
                     }
-                    (None, Some(external_resolver)) => {
+                    (None, Some(oracle_handler), _) => {
+                        Ok(oracle_handler(&foreign_call.inputs)?.into())
+                    }
+                    (None, None, Some(external_resolver)) => {
                         let encoded_params: Vec<_> =
                             foreign_call.inputs.iter().map(build_json_rpc_arg).collect();
 
@@ -315,7 +361,7 @@ impl ForeignCallExecutor for DefaultForeignCallExecutor {
 
                         Ok(parsed_response.into())
                     }
-                    (None, None) => panic!("Unknown foreign call {}", foreign_call_name),
+                    (None, None, None) => panic!("Unknown foreign call {}", foreign_call_name),
                 }
             }
         }
@@ -5,6 +5,7 @@ pub use self::compile::{
 pub use self::execute::execute_circuit;
 pub use self::foreign_calls::{
     DefaultForeignCallExecutor, ForeignCall, ForeignCallExecutor, NargoForeignCallResult,
+    OracleHandler,
 };
 pub use self::optimize::{optimize_contract, optimize_program};
 pub use self::transform::{transform_contract, transform_program};
@@ -0,0 +1,76 @@
+// Shares the driver's helpers for compiling in-memory programs.
+#[path = "../../../compiler/noirc_driver/tests/common/mod.rs"]
+mod common;
+
+use std::collections::BTreeMap;
+
+use acvm::{
+    acir::{
+        brillig::{ForeignCallResult, Value},
+        native_types::WitnessMap,
+    },
+    blackbox_solver::StubbedBlackBoxSolver,
+    FieldElement,
+};
+use nargo::ops::{execute_circuit, DefaultForeignCallExecutor};
+use noirc_driver::CompileOptions;
+use noirc_printable_type::ForeignCallError;
+
+use common::compile_main;
+
+fn compile_circuit(source: &str) -> acvm::acir::circuit::Circuit {
+    let (program, _) =
+        compile_main(source, &CompileOptions::default()).expect("program should compile");
+    program.program.functions.into_iter().next().unwrap()
+}
+
+#[test]
+fn executes_program_with_registered_oracle() {
+    let source = "
+#[oracle(double)]
+unconstrained fn double_oracle(_x: Field) -> Field {}
+
+unconstrained fn double(x: Field) -> Field {
+    double_oracle(x)
+}
+
+fn main(x: Field) -> pub Field {
+    let doubled = double(x);
+    assert(doubled == x + x);
+    doubled
+}";
+    let circuit = compile_circuit(source);
+
+    let mut foreign_call_executor = DefaultForeignCallExecutor::new(false, None);
+    foreign_call_executor
+        .register_oracle("double", |inputs| {
+            let x = inputs[0].unwrap_value().to_field();
+            Ok(ForeignCallResult::from(Value::from(x + x)))
+        })
+        .expect("double is not a builtin oracle");
+
+    let x = circuit.private_parameters.first().copied().unwrap();
+    let initial_witness = WitnessMap::from(BTreeMap::from([(x, FieldElement::from(21_u128))]));
+    let solved_witness = execute_circuit(
+        &circuit,
+        initial_witness,
+        &StubbedBlackBoxSolver,
+        &mut foreign_call_executor,
+    )
+    .expect("program should execute");
+
+    let return_witness = circuit.return_values.0.first().unwrap();
+    assert_eq!(solved_witness[return_witness], FieldElement::from(42_u128));
+}
+
+#[test]
+fn rejects_registering_builtin_oracle() {
+    let mut foreign_call_executor = DefaultForeignCallExecutor::new(false, None);
+    let result =
+        foreign_call_executor.register_oracle("print", |_| Ok(ForeignCallResult::default()));
+
+    assert!(matches!(
+        result,
+        Err(ForeignCallError::BuiltinOracleOverride(name)) if name == "print"
+    ));
+}
@@ -55,7 +55,7 @@ pub(crate) struct DebugCommand {
 
 pub(crate) fn run(
     backend: &Backend,
-    args: DebugCommand,
+    mut args: DebugCommand,
     config: NargoConfig,
 ) -> Result<(), CliError> {
     let acir_mode = args.acir_mode;
@@ -73,6 +73,7 @@ pub(crate) fn run(
         .compile_options
         .expression_width
         .unwrap_or_else(|| backend.get_backend_info_or_default());
+    args.compile_options.expression_width = Some(expression_width);
 
     let Some(package) = workspace.into_iter().find(|p| p.is_binary()) else {
         println!(
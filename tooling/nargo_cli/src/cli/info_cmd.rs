@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use acvm::acir::circuit::ExpressionWidth;
+use acvm::acir::circuit::{ExpressionWidth, Program};
 use backend_interface::BackendError;
 use clap::Args;
 use iter_extended::vecmap;
@@ -47,13 +47,18 @@ pub(crate) struct InfoCommand {
     #[clap(long, hide = true)]
     profile_info: bool,
 
+    /// Report a lower bound on the backend circuit size, computed from the ACIR opcodes without
+    /// using a backend
+    #[clap(long, conflicts_with = "expression_width")]
+    estimate: bool,
+
     #[clap(flatten)]
     compile_options: CompileOptions,
 }
 
 pub(crate) fn run(
     backend: &Backend,
-    args: InfoCommand,
+    mut args: InfoCommand,
     config: NargoConfig,
 ) -> Result<(), CliError> {
     let toml_path = get_package_manifest(&config.program_dir)?;
@@ -70,10 +75,15 @@ pub(crate) fn run(
     insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
     let parsed_files = parse_all(&workspace_file_manager);
 
-    let expression_width = args
-        .compile_options
-        .expression_width
-        .unwrap_or_else(|| backend.get_backend_info_or_default());
+    let expression_width = if args.estimate {
+        // The lower bound is computed for the arithmetic gates of Barretenberg.
+        ExpressionWidth::Bounded { width: bb_abstraction_leaks::GATE_WIDTH }
+    } else {
+        args.compile_options
+            .expression_width
+            .unwrap_or_else(|| backend.get_backend_info_or_default())
+    };
+    args.compile_options.expression_width = Some(expression_width);
     let compiled_workspace = compile_workspace(
         &workspace_file_manager,
         &parsed_files,
@@ -117,13 +127,21 @@ pub(crate) fn run(
     let program_info = binary_packages
         .par_bridge()
         .map(|(package, program)| {
-            count_opcodes_and_gates_in_program(backend, program, package, expression_width)
+            count_opcodes_and_gates_in_program(
+                backend,
+                program,
+                package,
+                expression_width,
+                args.estimate,
+            )
         })
         .collect::<Result<_, _>>()?;
 
     let contract_info = compiled_contracts
         .into_par_iter()
-        .map(|contract| count_opcodes_and_gates_in_contract(backend, contract, expression_width))
+        .map(|contract| {
+            count_opcodes_and_gates_in_contract(backend, contract, expression_width, args.estimate)
+        })
         .collect::<Result<_, _>>()?;
 
     let info_report = InfoReport { programs: program_info, contracts: contract_info };
@@ -133,8 +151,10 @@ pub(crate) fn run(
         println!("{}", serde_json::to_string(&info_report).unwrap());
     } else {
         // Otherwise print human-readable table.
+        let circuit_size_header =
+            if args.estimate { "Minimum Backend Circuit Size" } else { "Backend Circuit Size" };
         if !info_report.programs.is_empty() {
-            let mut program_table = table!([Fm->"Package", Fm->"Expression Width", Fm->"ACIR Opcodes", Fm->"Backend Circuit Size"]);
+            let mut program_table = table!([Fm->"Package", Fm->"Expression Width", Fm->"ACIR Opcodes", Fm->circuit_size_header]);
 
             for program in info_report.programs {
                 program_table.add_row(program.into());
@@ -147,7 +167,7 @@ pub(crate) fn run(
                 Fm->"Function",
                 Fm->"Expression Width",
                 Fm->"ACIR Opcodes",
-                Fm->"Backend Circuit Size"
+                Fm->circuit_size_header
             ]);
             for contract_info in info_report.contracts {
                 let contract_rows: Vec<Row> = contract_info.into();
@@ -272,13 +292,14 @@ fn count_opcodes_and_gates_in_program(
     compiled_program: CompiledProgram,
     package: &Package,
     expression_width: ExpressionWidth,
+    estimate: bool,
 ) -> Result<ProgramInfo, CliError> {
     Ok(ProgramInfo {
         name: package.name.to_string(),
         expression_width,
         // TODO(https://github.com/noir-lang/noir/issues/4428)
         acir_opcodes: compiled_program.program.functions[0].opcodes.len(),
-        circuit_size: backend.get_exact_circuit_size(&compiled_program.program)?,
+        circuit_size: circuit_size(backend, &compiled_program.program, estimate)?,
     })
 }
 
@@ -286,6 +307,7 @@ fn count_opcodes_and_gates_in_contract(
     backend: &Backend,
     contract: CompiledContract,
     expression_width: ExpressionWidth,
+    estimate: bool,
 ) -> Result<ContractInfo, CliError> {
     let functions = contract
         .functions
@@ -295,10 +317,20 @@ fn count_opcodes_and_gates_in_contract(
                 name: function.name,
                 // TODO(https://github.com/noir-lang/noir/issues/4428)
                 acir_opcodes: function.bytecode.functions[0].opcodes.len(),
-                circuit_size: backend.get_exact_circuit_size(&function.bytecode)?,
+                circuit_size: circuit_size(backend, &function.bytecode, estimate)?,
             })
         })
         .collect::<Result<_, _>>()?;
 
     Ok(ContractInfo { name: contract.name, expression_width, functions })
 }
+
+fn circuit_size(backend: &Backend, program: &Program, estimate: bool) -> Result<u32, BackendError> {
+    if estimate {
+        // TODO(https://github.com/noir-lang/noir/issues/4428)
+        let circuit = &program.functions[0];
+        Ok(bb_abstraction_leaks::circuit_size_lower_bound(circuit) as u32)
+    } else {
+        backend.get_exact_circuit_size(program)
+    }
+}
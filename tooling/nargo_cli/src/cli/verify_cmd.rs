@@ -37,7 +37,7 @@ pub(crate) struct VerifyCommand {
 
 pub(crate) fn run(
     backend: &Backend,
-    args: VerifyCommand,
+    mut args: VerifyCommand,
     config: NargoConfig,
 ) -> Result<(), CliError> {
     let toml_path = get_package_manifest(&config.program_dir)?;
@@ -58,6 +58,7 @@ pub(crate) fn run(
         .compile_options
         .expression_width
         .unwrap_or_else(|| backend.get_backend_info_or_default());
+    args.compile_options.expression_width = Some(expression_width);
     let binary_packages = workspace.into_iter().filter(|package| package.is_binary());
     for package in binary_packages {
         let compilation_result = compile_program(
@@ -0,0 +1,32 @@
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathAssert, PathChild};
+use predicates::prelude::*;
+use std::process::Command;
+
+#[test]
+fn info_estimate_does_not_use_backend() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+    test_dir
+        .child("Nargo.toml")
+        .write_str("[package]\nname = \"multiplication\"\ntype = \"bin\"\nauthors = [\"\"]\n")
+        .unwrap();
+    test_dir
+        .child("src/main.nr")
+        .write_str(
+            "fn main(x: Field, y: pub Field) {
+    assert(x * y == 6);
+}",
+        )
+        .unwrap();
+
+    // Any attempt to use the backend would either fail or install it at this path.
+    let backend_path = test_dir.child("backend");
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", backend_path.path());
+    cmd.arg("--program-dir").arg(test_dir.path());
+    cmd.arg("info").arg("--estimate").arg("--json");
+
+    cmd.assert().success().stdout(predicate::str::contains("\"circuit_size\":1"));
+    backend_path.assert(predicate::path::missing());
+}
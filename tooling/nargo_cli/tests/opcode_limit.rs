@@ -0,0 +1,32 @@
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use predicates::prelude::*;
+use std::process::Command;
+
+test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
+
+#[test]
+fn opcode_limit_counts_circuit_transformed_for_backend_width() {
+    let test_dir = assert_fs::TempDir::new().unwrap();
+    test_dir
+        .child("Nargo.toml")
+        .write_str("[package]\nname = \"wide_expression\"\ntype = \"bin\"\nauthors = [\"\"]\n")
+        .unwrap();
+    // A single assertion over six witnesses, which the mock backend's width of 3 splits into
+    // several opcodes.
+    test_dir
+        .child("src/main.nr")
+        .write_str(
+            "fn main(a: Field, b: Field, c: Field, d: Field, e: Field, y: pub Field) {
+    assert(a + b + c + d + e == y);
+}",
+        )
+        .unwrap();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(test_dir.path());
+    cmd.arg("execute").arg("--max-opcodes").arg("1");
+
+    cmd.assert().failure().stderr(predicate::str::contains("exceeds the limit of 1"));
+}
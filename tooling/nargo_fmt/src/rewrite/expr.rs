@@ -28,6 +28,7 @@ pub(crate) fn rewrite(
             let op = match prefix.operator {
                 UnaryOp::Minus => "-",
                 UnaryOp::Not => "!",
+                UnaryOp::BitNot => "~",
                 UnaryOp::MutableReference => "&mut ",
                 UnaryOp::Dereference { implicitly_added } => {
                     if implicitly_added {
@@ -98,6 +99,10 @@ pub(crate) fn rewrite(
 
             format!("{collection}{index}")
         }
+        ExpressionKind::SubArray(sub_array) => {
+            let collection = rewrite_sub_expr(visitor, shape, sub_array.collection);
+            format!("{collection}[{}..{}]", sub_array.start, sub_array.end)
+        }
         ExpressionKind::Tuple(exprs) => format_parens(
             None,
             visitor.fork(),
@@ -110,6 +115,7 @@ pub(crate) fn rewrite(
         ),
         ExpressionKind::Literal(literal) => match literal {
             Literal::Integer(_, _)
+            | Literal::Char(_)
             | Literal::Bool(_)
             | Literal::Str(_)
             | Literal::RawStr(..)
@@ -91,7 +91,7 @@ impl super::FmtVisitor<'_> {
                     let result = format!("for {identifier} in {range} {block}");
                     self.push_rewrite(result, span);
                 }
-                StatementKind::Assign(_) => {
+                StatementKind::Assign(_) | StatementKind::Function(_) => {
                     self.push_rewrite(self.slice(span).to_string(), span);
                 }
                 StatementKind::Error => unreachable!(),
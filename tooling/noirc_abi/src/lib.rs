@@ -200,6 +200,28 @@ impl AbiType {
             AbiType::String { length } => *length as u32,
         }
     }
+
+    /// Returns the type of each field element used to represent the type once encoded,
+    /// in the order in which they are encoded.
+    pub fn field_types(&self) -> Vec<AbiType> {
+        match self {
+            AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean => vec![self.clone()],
+            AbiType::Array { length, typ } => {
+                let element_types = typ.field_types();
+                (0..*length).flat_map(|_| element_types.iter().cloned()).collect()
+            }
+            AbiType::Struct { fields, .. } => {
+                fields.iter().flat_map(|(_, field_type)| field_type.field_types()).collect()
+            }
+            AbiType::Tuple { fields } => {
+                fields.iter().flat_map(|field_type| field_type.field_types()).collect()
+            }
+            // Strings are encoded as one field element per byte.
+            AbiType::String { length } => {
+                vec![AbiType::Integer { sign: Sign::Unsigned, width: 8 }; *length as usize]
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -257,6 +279,21 @@ impl Abi {
         self.return_type.is_none() && self.parameters.is_empty()
     }
 
+    /// Returns the witness of each field element of the ABI's input together with its type,
+    /// ordered by witness index.
+    pub fn input_witness_types(&self) -> Vec<(Witness, AbiType)> {
+        let mut witness_types: Vec<_> = self
+            .parameters
+            .iter()
+            .flat_map(|param| {
+                let witnesses = range_to_vec(&self.param_witnesses[&param.name]);
+                witnesses.into_iter().zip(param.typ.field_types())
+            })
+            .collect();
+        witness_types.sort_by_key(|(witness, _)| *witness);
+        witness_types
+    }
+
     pub fn to_btree_map(&self) -> BTreeMap<String, AbiType> {
         let mut map = BTreeMap::new();
         for param in self.parameters.iter() {